@@ -12,7 +12,7 @@
     let
       crates = builtins.map (item: item.name) (
         builtins.filter (
-          item: item.value == "directory" && builtins.pathExists "${root}/src/${item.name}/Cargo.toml"
+          item: item.value == "directory" && builtins.pathExists "${root}/src/${item.name}/src/main.rs"
         ) (lib.attrsToList (builtins.readDir "${root}/src"))
       );
     in
@@ -45,7 +45,14 @@ in
         name = "grind-${crate}";
         pname = "grind-${crate}";
         version = version;
-        src = "${root}/src/${crate}";
+        src = root;
+        cargoBuildOptions =
+          options:
+          options
+          ++ [
+            "--package"
+            crate
+          ];
       }
       // (
         if isExe then
@@ -0,0 +1,75 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day01>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day01;
+
+impl Solution for Day01 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 1;
+
+  type Input = (Vec<u32>, Vec<u32>);
+  type Part1 = u32;
+  type Part2 = u32;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let iter = input
+      .split("\n")
+      .enumerate()
+      .filter(|(_, numbers)| !numbers.is_empty())
+      .map(|(index, list)| -> anyhow::Result<(u32, u32)> {
+        list
+          .split("   ")
+          .map(|num| num.trim().parse::<u32>())
+          .process_results(|nums| nums.collect_tuple())
+          .map_err(anyhow::Error::from)
+          .and_then(|nums| {
+            nums.ok_or_else(|| anyhow::anyhow!("Expected two location ids"))
+          })
+          .with_context(|| {
+            format!("Invalid line {}: {list:?}", index.saturating_add(1))
+          })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let first = iter.iter().map(|item| item.0).sorted().collect::<Vec<_>>();
+    let second = iter.iter().map(|item| item.1).sorted().collect::<Vec<_>>();
+
+    Ok((first, second))
+  }
+
+  fn part1((first, second): &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(distance(first, second))
+  }
+
+  fn part2((first, second): &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(similarity(first, second))
+  }
+}
+
+fn distance(first: &[u32], second: &[u32]) -> u32 {
+  first.iter().zip(second.iter()).fold(0u32, |acc, next| {
+    acc.saturating_add(next.1.abs_diff(*next.0))
+  })
+}
+
+fn similarity(first: &[u32], second: &[u32]) -> u32 {
+  first.iter().fold(0u32, |acc, next| {
+    acc.saturating_add(
+      (second.iter().filter(|second| **second == *next).count() as u32)
+        .saturating_mul(*next),
+    )
+  })
+}
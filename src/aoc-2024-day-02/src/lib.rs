@@ -0,0 +1,93 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day02;
+
+impl Solution for Day02 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 2;
+
+  type Input = Vec<Vec<u32>>;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let reports = input
+      .split("\n")
+      .enumerate()
+      .filter(|(_, numbers)| !numbers.is_empty())
+      .map(|(index, report)| {
+        report
+          .split(" ")
+          .map(|num| num.trim().parse::<u32>())
+          .collect::<Result<Vec<_>, _>>()
+          .with_context(|| {
+            format!(
+              "Invalid report on line {}: {report:?}",
+              index.saturating_add(1)
+            )
+          })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(reports)
+  }
+
+  fn part1(reports: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(safe(reports))
+  }
+
+  fn part2(reports: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(tolerant(reports))
+  }
+}
+
+fn safe(reports: &[Vec<u32>]) -> usize {
+  reports
+    .iter()
+    .filter(|report| {
+      (report.is_sorted_by(|a, b| a >= b) || report.is_sorted_by(|a, b| a <= b))
+        && (report
+          .iter()
+          .take(report.len().saturating_sub(1))
+          .zip(report.iter().skip(1))
+          .all(|(x, y)| {
+            let diff = x.abs_diff(*y);
+            (1..=3).contains(&diff)
+          }))
+    })
+    .count()
+}
+
+fn tolerant(reports: &[Vec<u32>]) -> usize {
+  reports
+    .iter()
+    .filter(|report| {
+      report.iter().enumerate().any(|(i, _)| {
+        let mut r#try = (*report).clone();
+        r#try.remove(i);
+        (r#try.is_sorted_by(|a, b| a >= b) || r#try.is_sorted_by(|a, b| a <= b))
+          && (r#try
+            .iter()
+            .take(r#try.len().saturating_sub(1))
+            .zip(r#try.iter().skip(1))
+            .all(|(x, y)| {
+              let diff = x.abs_diff(*y);
+              (1..=3).contains(&diff)
+            }))
+      })
+    })
+    .count()
+}
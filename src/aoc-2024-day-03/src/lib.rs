@@ -0,0 +1,143 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day03>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day03;
+
+impl Solution for Day03 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 3;
+
+  type Input = Program;
+  type Part1 = u32;
+  type Part2 = u32;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let multiply_statements = MULTIPLY_STATEMENT_REGEX
+      .captures_iter(input)
+      .map(|r#match| -> anyhow::Result<(usize, u32, u32)> {
+        let (Some(statement), Some(x), Some(y)) =
+          (r#match.get(0), r#match.get(1), r#match.get(2))
+        else {
+          return Err(anyhow::anyhow!("Incomplete multiply statement"));
+        };
+        let line = input
+          .get(..statement.start())
+          .map_or(0, |before| before.matches('\n').count())
+          .saturating_add(1);
+        let parse = |operand: regex::Match| {
+          operand.as_str().parse::<u32>().with_context(|| {
+            format!(
+              "Invalid multiply statement on line {line}: {:?}",
+              statement.as_str()
+            )
+          })
+        };
+
+        Ok((statement.start(), parse(x)?, parse(y)?))
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let do_statements = DO_STATEMENT_REGEX
+      .find_iter(input)
+      .map(|r#match| r#match.start())
+      .collect::<Vec<_>>();
+
+    let do_not_statements = DO_NOT_STATEMENT_REGEX
+      .find_iter(input)
+      .map(|r#match| r#match.start())
+      .collect::<Vec<_>>();
+
+    Ok(Program {
+      multiply_statements,
+      do_statements,
+      do_not_statements,
+    })
+  }
+
+  fn part1(
+    Program {
+      multiply_statements,
+      ..
+    }: &Self::Input,
+  ) -> anyhow::Result<Self::Part1> {
+    Ok(multiply_statements.iter().fold(0u32, |acc, next| {
+      let x = next.1;
+      let y = next.2;
+      acc.saturating_add(x.saturating_mul(y))
+    }))
+  }
+
+  fn part2(
+    Program {
+      multiply_statements,
+      do_statements,
+      do_not_statements,
+    }: &Self::Input,
+  ) -> anyhow::Result<Self::Part2> {
+    Ok(multiply_statements.iter().fold(0u32, |acc, next| {
+      let start = next.0;
+
+      let previous_do = do_statements.iter().rev().find(|r#do| **r#do < start);
+      let previous_do_not = do_not_statements
+        .iter()
+        .rev()
+        .find(|do_not| **do_not < start);
+
+      let x = next.1;
+      let y = next.2;
+      let mul = x.saturating_mul(y);
+
+      match (previous_do, previous_do_not) {
+        (None, None) => acc.saturating_add(mul),
+        (None, Some(_)) => acc,
+        (Some(_), None) => acc.saturating_add(mul),
+        (Some(previous_do), Some(previous_do_not)) => {
+          if previous_do > previous_do_not {
+            acc.saturating_add(mul)
+          } else {
+            acc
+          }
+        }
+      }
+    }))
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Program {
+  multiply_statements: Vec<(usize, u32, u32)>,
+  do_statements: Vec<usize>,
+  do_not_statements: Vec<usize>,
+}
+
+lazy_static::lazy_static! {
+  static ref MULTIPLY_STATEMENT_REGEX: regex::Regex = {
+    #[allow(clippy::unwrap_used, reason = "Valid regex")]
+    let regex = regex::Regex::new(r"mul\(([1-9][0-9]*),([1-9][0-9]*)\)").unwrap();
+    regex
+  };
+
+  static ref DO_STATEMENT_REGEX: regex::Regex = {
+    #[allow(clippy::unwrap_used, reason = "Valid regex")]
+    let regex = regex::Regex::new(r"do\(\)").unwrap();
+    regex
+  };
+
+  static ref DO_NOT_STATEMENT_REGEX: regex::Regex = {
+    #[allow(clippy::unwrap_used, reason = "Valid regex")]
+    let regex = regex::Regex::new(r"don't\(\)").unwrap();
+    regex
+  };
+}
@@ -0,0 +1,89 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::Vector2;
+use grind_grid::Grid;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day04>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day04;
+
+impl Solution for Day04 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 4;
+
+  type Input = Grid<char>;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    input.parse::<Grid<char>>()
+  }
+
+  fn part1(grid: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(
+      grid
+        .find_all(|&letter| letter == 'X')
+        .map(|position| {
+          Vector2::ADJACENT
+            .into_iter()
+            .filter(|&direction| {
+              (1..=3)
+                .map(|distance| {
+                  direction
+                    .checked_mul(distance)
+                    .and_then(|offset| grid.offset(position, offset))
+                    .and_then(|position| grid.get(position))
+                    .copied()
+                })
+                .eq(XMAS_TAIL.map(Some))
+            })
+            .count()
+        })
+        .sum::<usize>(),
+    )
+  }
+
+  fn part2(grid: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(
+      grid
+        .find_all(|&letter| letter == 'A')
+        .filter(|&position| {
+          let corners = CORNERS.map(|corner| {
+            grid
+              .offset(position, corner)
+              .and_then(|position| grid.get(position))
+              .copied()
+          });
+          X_MAS_CORNERS
+            .iter()
+            .any(|letters| corners == letters.map(Some))
+        })
+        .count(),
+    )
+  }
+}
+
+const XMAS_TAIL: [char; 3] = ['M', 'A', 'S'];
+
+const CORNERS: [Vector2; 4] = [
+  Vector2::NORTH_WEST,
+  Vector2::NORTH_EAST,
+  Vector2::SOUTH_EAST,
+  Vector2::SOUTH_WEST,
+];
+
+const X_MAS_CORNERS: [[char; 4]; 4] = [
+  ['S', 'S', 'M', 'M'],
+  ['M', 'S', 'S', 'M'],
+  ['M', 'M', 'S', 'S'],
+  ['S', 'M', 'M', 'S'],
+];
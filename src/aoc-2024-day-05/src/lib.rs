@@ -0,0 +1,130 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_algo::toposort;
+use grind_core::{Day, Puzzle, Solution, Year};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day05>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day05;
+
+impl Solution for Day05 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 5;
+
+  type Input = Manual;
+  type Part1 = u32;
+  type Part2 = u32;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let (rules, updates) = input
+      .split_once("\n\n")
+      .ok_or_else(|| anyhow::anyhow!("Missing blank line after rules"))?;
+
+    let rules = rules
+      .trim()
+      .split("\n")
+      .enumerate()
+      .map(|(index, rule)| {
+        rule
+          .split_once("|")
+          .map(|(before, after)| {
+            (before.trim().to_owned(), after.trim().to_owned())
+          })
+          .ok_or_else(|| {
+            anyhow::anyhow!(
+              "Invalid rule on line {}: {rule:?}",
+              index.saturating_add(1)
+            )
+          })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let updates = updates
+      .trim()
+      .split("\n")
+      .map(|update| {
+        update
+          .split(",")
+          .map(|page| page.trim().to_owned())
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+
+    Ok(Manual { rules, updates })
+  }
+
+  fn part1(
+    Manual { rules, updates }: &Self::Input,
+  ) -> anyhow::Result<Self::Part1> {
+    updates
+      .iter()
+      .filter(|update| {
+        rules
+          .iter()
+          .filter(|rule| update.contains(&rule.0) && update.contains(&rule.1))
+          .all(|rule| {
+            update.iter().position(|page| page == &rule.0)
+              < update.iter().position(|page| page == &rule.1)
+          })
+      })
+      .map(|update| middle_page(update))
+      .sum::<anyhow::Result<u32>>()
+  }
+
+  fn part2(
+    Manual { rules, updates }: &Self::Input,
+  ) -> anyhow::Result<Self::Part2> {
+    updates
+      .iter()
+      .filter(|update| {
+        !rules
+          .iter()
+          .filter(|rule| update.contains(&rule.0) && update.contains(&rule.1))
+          .all(|rule| {
+            update.iter().position(|page| page == &rule.0)
+              < update.iter().position(|page| page == &rule.1)
+          })
+      })
+      .map(|update| {
+        let relevant_rules = rules
+          .iter()
+          .filter(|rule| update.contains(&rule.0) && update.contains(&rule.1))
+          .cloned();
+
+        let ordered = toposort(update.iter().cloned(), relevant_rules)
+          .map_err(|cycle| {
+            anyhow::anyhow!(
+              "Rules for update {:?} go around in a cycle through {:?}",
+              update.join(","),
+              cycle.join(",")
+            )
+          })?;
+
+        middle_page(&ordered)
+      })
+      .sum::<anyhow::Result<u32>>()
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Manual {
+  rules: Vec<(String, String)>,
+  updates: Vec<Vec<String>>,
+}
+
+fn middle_page(update: &[String]) -> anyhow::Result<u32> {
+  update
+    .get(update.len() / 2)
+    .ok_or_else(|| anyhow::anyhow!("Empty update"))?
+    .parse::<u32>()
+    .with_context(|| format!("Invalid page in update {:?}", update.join(",")))
+}
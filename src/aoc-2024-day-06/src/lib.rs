@@ -0,0 +1,160 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::convert::Infallible;
+
+use grind_algo::Stepper;
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::{Direction, Directions, Point2};
+use grind_grid::{BitGrid, Grid};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day06>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day06;
+
+impl Solution for Day06 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 6;
+
+  type Input = Map;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    input.parse::<Map>()
+  }
+
+  fn part1(map: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(guard_walk(map)?.visited.count())
+  }
+
+  fn part2(map: &Self::Input) -> anyhow::Result<Self::Part2> {
+    let walk = guard_walk(map)?;
+    let (start, direction) = find_guard(map)?;
+    let mut obstructed = map.clone();
+    let mut loops = 0_usize;
+    for position in walk.visited.positions() {
+      if position == start {
+        continue;
+      }
+
+      let Some(object) = obstructed.get_mut(position) else {
+        continue;
+      };
+      let previous_object = std::mem::replace(object, OBSTRUCTION_CHAR);
+      let mut obstructed_guard = Guard::new(&obstructed, start, direction)?;
+      obstructed_guard.run_to_fixpoint()?;
+      if obstructed_guard.looping {
+        loops = loops.saturating_add(1);
+      }
+      if let Some(object) = obstructed.get_mut(position) {
+        *object = previous_object;
+      }
+    }
+
+    Ok(loops)
+  }
+}
+
+type Map = Grid<char>;
+
+/// Guard after walking off the map.
+fn guard_walk(map: &Map) -> anyhow::Result<Guard<'_>> {
+  let (start, direction) = find_guard(map)?;
+  let mut guard = Guard::new(map, start, direction)?;
+  guard.run_to_fixpoint()?;
+  if guard.looping {
+    return Err(anyhow::anyhow!("Guard never leaves the map"));
+  }
+
+  Ok(guard)
+}
+
+fn find_guard(map: &Map) -> anyhow::Result<(Point2, Direction)> {
+  let position = map
+    .find(|object| GUARD_CHARS.contains(object))
+    .ok_or_else(|| anyhow::anyhow!("Guard not found"))?;
+  let direction = match map.get(position) {
+    Some('^') => Direction::North,
+    Some('>') => Direction::East,
+    Some('v') => Direction::South,
+    Some('<') => Direction::West,
+    _ => return Err(anyhow::anyhow!("Invalid guard at {position}")),
+  };
+
+  Ok((position, direction))
+}
+
+/// Guard walking ahead and turning right in front of obstructions along with
+/// everywhere it went.
+#[derive(Debug, Clone)]
+struct Guard<'a> {
+  map: &'a Map,
+  /// Nothing once the guard left the map.
+  position: Option<Point2>,
+  direction: Direction,
+  visited: BitGrid,
+  /// Directions the guard faced on each position.
+  headings: Grid<Directions>,
+  /// Whether the guard faced the same way on the same position twice.
+  looping: bool,
+}
+
+impl<'a> Guard<'a> {
+  fn new(
+    map: &'a Map,
+    position: Point2,
+    direction: Direction,
+  ) -> anyhow::Result<Self> {
+    let mut guard = Self {
+      map,
+      position: Some(position),
+      direction,
+      visited: BitGrid::new(map.width(), map.height())?,
+      headings: Grid::new(map.width(), map.height(), Directions::NONE)?,
+      looping: false,
+    };
+    guard.record(position);
+
+    Ok(guard)
+  }
+
+  fn record(&mut self, position: Point2) {
+    self.visited.insert(position);
+    if let Some(headings) = self.headings.get_mut(position) {
+      self.looping = !headings.insert(self.direction);
+    }
+  }
+}
+
+impl Stepper for Guard<'_> {
+  type Error = Infallible;
+
+  fn step(&mut self) -> Result<bool, Self::Error> {
+    let Some(position) = self.position.filter(|_| !self.looping) else {
+      return Ok(false);
+    };
+
+    let ahead = self.map.offset(position, self.direction.offset());
+    if ahead.and_then(|ahead| self.map.get(ahead)) == Some(&OBSTRUCTION_CHAR) {
+      self.direction = self.direction.turn_right();
+    } else {
+      self.position = ahead;
+    }
+    if let Some(position) = self.position {
+      self.record(position);
+    }
+
+    Ok(true)
+  }
+}
+
+const GUARD_CHARS: [char; 4] = ['^', '>', 'v', '<'];
+const OBSTRUCTION_CHAR: char = '#';
@@ -0,0 +1,131 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_math::{assignments, concat_digits};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day07>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day07;
+
+impl Solution for Day07 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 7;
+
+  type Input = Vec<Equation>;
+  type Part1 = u64;
+  type Part2 = u64;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let input = input.trim();
+
+    let equations = input
+      .split("\n")
+      .enumerate()
+      .map(|(index, equation)| {
+        parse_equation(equation).with_context(|| {
+          format!(
+            "Invalid equation on line {}: {equation:?}",
+            index.saturating_add(1)
+          )
+        })
+      })
+      .process_results(|equations| equations.collect::<Vec<_>>())?;
+
+    Ok(equations)
+  }
+
+  fn part1(equations: &Self::Input) -> anyhow::Result<Self::Part1> {
+    equations.iter().try_fold(0u64, |sum, equation| {
+      if equation.solvable_with(&ADD_MUL_OPERATORS)? {
+        Ok(sum.saturating_add(equation.solution))
+      } else {
+        Ok(sum)
+      }
+    })
+  }
+
+  fn part2(equations: &Self::Input) -> anyhow::Result<Self::Part2> {
+    equations.iter().try_fold(0u64, |sum, equation| {
+      if equation.solvable_with(&ADD_MUL_CONCAT_OPERATORS)? {
+        Ok(sum.saturating_add(equation.solution))
+      } else {
+        Ok(sum)
+      }
+    })
+  }
+}
+
+const ADD_MUL_OPERATORS: [char; 2] = ['+', '*'];
+const ADD_MUL_CONCAT_OPERATORS: [char; 3] = ['+', '*', '|'];
+
+fn parse_equation(equation: &str) -> anyhow::Result<Equation> {
+  let (solution, operands) = equation
+    .trim()
+    .split_once(":")
+    .ok_or_else(|| anyhow::anyhow!("Failed parsing equation"))?;
+
+  let solution = solution.trim().parse::<u64>()?;
+
+  let operands = operands
+    .trim()
+    .split(" ")
+    .map(|operand| operand.trim().parse::<u64>())
+    .process_results(|operands| operands.collect::<Vec<_>>())?;
+
+  Ok(Equation { solution, operands })
+}
+
+#[derive(Clone, Debug)]
+struct Equation {
+  solution: u64,
+  operands: Vec<u64>,
+}
+
+impl Equation {
+  /// Whether putting some of `operators` between the operands left to right
+  /// gives the solution.
+  fn solvable_with(&self, operators: &[char]) -> anyhow::Result<bool> {
+    let Some((&first_operand, operands)) = self.operands.split_first() else {
+      return Ok(false);
+    };
+
+    for choices in assignments(operators.len(), operands.len()) {
+      let solution = choices.iter().zip(operands.iter()).try_fold(
+        first_operand,
+        |solution, (&choice, &operand)| {
+          apply(operators.get(choice).copied(), solution, operand)
+        },
+      )?;
+      if solution == self.solution {
+        return Ok(true);
+      }
+    }
+
+    Ok(false)
+  }
+}
+
+fn apply(
+  operator: Option<char>,
+  solution: u64,
+  operand: u64,
+) -> anyhow::Result<u64> {
+  match operator {
+    Some('+') => Ok(solution.saturating_add(operand)),
+    Some('*') => Ok(solution.saturating_mul(operand)),
+    Some('|') => concat_digits(solution, operand).ok_or_else(|| {
+      anyhow::anyhow!("Concatenating {solution} and {operand} overflows")
+    }),
+    _ => Ok(solution),
+  }
+}
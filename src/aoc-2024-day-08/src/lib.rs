@@ -0,0 +1,143 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::collections::HashMap;
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::Point2;
+use grind_grid::{BitGrid, Grid, Tile};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day08>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day08;
+
+impl Solution for Day08 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 8;
+
+  type Input = Grid<MapPosition>;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    input.parse::<Grid<MapPosition>>()
+  }
+
+  fn part1(map: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(mark_antinodes(map)?.antinodes.count())
+  }
+
+  fn part2(map: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(mark_antinodes(map)?.harmonic_antinodes.count())
+  }
+}
+
+#[allow(dead_code, reason = "debug")]
+fn serialize_map(map: &Grid<MapPosition>, antinodes: &BitGrid) -> String {
+  map
+    .map(|position, map_position| {
+      if antinodes.get(position) {
+        '#'
+      } else {
+        map_position.to_char()
+      }
+    })
+    .to_string()
+}
+
+struct Antinodes {
+  antinodes: BitGrid,
+  harmonic_antinodes: BitGrid,
+}
+
+fn mark_antinodes(map: &Grid<MapPosition>) -> anyhow::Result<Antinodes> {
+  let mut antinodes = BitGrid::new(map.width(), map.height())?;
+  let mut harmonic_antinodes = antinodes.clone();
+
+  let antennas = map
+    .iter()
+    .filter_map(|(position, map_position)| {
+      map_position.antenna.map(|antenna| Antenna {
+        position,
+        frequency: antenna,
+      })
+    })
+    .collect::<Vec<_>>();
+
+  let mut antenna_map: HashMap<char, Vec<Antenna>> = HashMap::new();
+  for antenna in antennas.iter() {
+    if let Some(antennas) = antenna_map.get_mut(&antenna.frequency) {
+      antennas.push(antenna.clone());
+    } else {
+      antenna_map.insert(antenna.frequency, vec![antenna.clone()]);
+    }
+  }
+
+  for antenna in antennas.iter() {
+    let other_antennas = antenna_map[&antenna.frequency]
+      .iter()
+      .filter(|other_antenna| other_antenna.position != antenna.position)
+      .collect::<Vec<_>>();
+
+    for other_antenna in other_antennas.iter() {
+      let Some(distance) = antenna.position.diff(other_antenna.position) else {
+        continue;
+      };
+
+      if let Some(antinode_position) =
+        distance.checked_mul(2).and_then(|antinode_distance| {
+          map.offset(antenna.position, antinode_distance)
+        })
+      {
+        antinodes.insert(antinode_position);
+      }
+
+      for mul in 1.. {
+        let Some(antinode_position) =
+          distance.checked_mul(mul).and_then(|antinode_distance| {
+            map.offset(antenna.position, antinode_distance)
+          })
+        else {
+          break;
+        };
+
+        harmonic_antinodes.insert(antinode_position);
+      }
+    }
+  }
+
+  Ok(Antinodes {
+    antinodes,
+    harmonic_antinodes,
+  })
+}
+
+#[derive(Clone, Debug)]
+struct MapPosition {
+  antenna: Option<char>,
+}
+
+impl Tile for MapPosition {
+  fn from_char(char: char) -> anyhow::Result<Self> {
+    Ok(Self {
+      antenna: if char == '.' { None } else { Some(char) },
+    })
+  }
+
+  fn to_char(&self) -> char {
+    self.antenna.unwrap_or('.')
+  }
+}
+
+#[derive(Clone, Debug)]
+struct Antenna {
+  position: Point2,
+  frequency: char,
+}
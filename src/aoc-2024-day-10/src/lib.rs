@@ -0,0 +1,108 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use grind_algo::{bfs, Graph, Memo, Search};
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::Point2;
+use grind_grid::Grid;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day10>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day10;
+
+impl Solution for Day10 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 10;
+
+  type Input = Map;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let heights = input.parse::<Grid<char>>()?.try_map(|position, char| {
+      char
+        .to_digit(10)
+        .ok_or_else(|| anyhow::anyhow!("Invalid height {char:?} at {position}"))
+    })?;
+
+    Ok(Map { heights })
+  }
+
+  fn part1(map: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(map.trails().map(|trails| trails.goals().len()).sum())
+  }
+
+  fn part2(map: &Self::Input) -> anyhow::Result<Self::Part2> {
+    let mut memo = Memo::new();
+    Ok(
+      map
+        .heights
+        .find_all(|&height| height == TRAILHEAD_HEIGHT)
+        .map(|trailhead| map.rating(trailhead, &mut memo))
+        .fold(0, usize::saturating_add),
+    )
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+  heights: Grid<u32>,
+}
+
+impl Map {
+  /// Every trail from each trailhead up to the summits it reaches.
+  fn trails(&self) -> impl Iterator<Item = Search<Point2, usize>> + '_ {
+    self
+      .heights
+      .find_all(|&height| height == TRAILHEAD_HEIGHT)
+      .map(|trailhead| {
+        bfs(self, [trailhead], |&position| {
+          self.heights.get(position) == Some(&SUMMIT_HEIGHT)
+        })
+      })
+  }
+
+  /// How many distinct trails lead from `position` up to any summit.
+  fn rating(&self, position: Point2, memo: &mut Memo<Point2, usize>) -> usize {
+    if self.heights.get(position) == Some(&SUMMIT_HEIGHT) {
+      return 1;
+    }
+
+    memo.get_or_insert_with(position, |memo| {
+      self
+        .successors(&position)
+        .map(|(next, _)| self.rating(next, memo))
+        .fold(0, usize::saturating_add)
+    })
+  }
+}
+
+impl Graph for Map {
+  type State = Point2;
+  type Cost = usize;
+
+  fn successors(
+    &self,
+    position: &Point2,
+  ) -> impl Iterator<Item = (Point2, usize)> {
+    let uphill = self
+      .heights
+      .get(*position)
+      .map(|height| height.saturating_add(1));
+    self
+      .heights
+      .neighbours4(*position)
+      .filter(move |&next| self.heights.get(next).copied() == uphill)
+      .map(|next| (next, 1))
+  }
+}
+
+const TRAILHEAD_HEIGHT: u32 = 0;
+const SUMMIT_HEIGHT: u32 = 9;
@@ -0,0 +1,119 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_algo::Memo;
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_math::{digit_count, split_digits};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day11>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day11;
+
+impl Solution for Day11 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 11;
+
+  type Input = StoneList;
+  type Part1 = Stone;
+  type Part2 = Stone;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let input = input.trim();
+
+    let input_stones = input
+      .split(" ")
+      .map(|stone| {
+        stone
+          .trim()
+          .parse::<Stone>()
+          .with_context(|| format!("Invalid stone {stone:?}"))
+      })
+      .collect::<anyhow::Result<StoneList>>()?;
+
+    Ok(input_stones)
+  }
+
+  fn part1(input_stones: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(blink(input_stones, 25))
+  }
+
+  fn part2(input_stones: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(blink(input_stones, 75))
+  }
+}
+
+fn blink(stones: &StoneList, blinks: Blink) -> Stone {
+  let mut memo = Memo::new();
+
+  stones
+    .iter()
+    .map(|stone| blink_many(*stone, blinks, &mut memo))
+    .fold(0, Stone::saturating_add)
+}
+
+fn blink_many(stone: Stone, blinks: Blink, memo: &mut StoneMemo) -> Stone {
+  let Some(remaining) = blinks.checked_sub(1) else {
+    return 1;
+  };
+
+  memo.get_or_insert_with((stone, blinks), |memo| {
+    blink_once(stone)
+      .into_iter()
+      .map(|stone| blink_many(stone, remaining, memo))
+      .fold(0, Stone::saturating_add)
+  })
+}
+
+fn blink_once(stone: Stone) -> StoneList {
+  if stone == 0 {
+    return vec![1 as Stone];
+  }
+
+  let digits = digit_count(stone);
+  if digits.is_multiple_of(2) {
+    let (lhs_stone, rhs_stone) = split_digits(stone, digits.saturating_div(2));
+    return vec![lhs_stone, rhs_stone];
+  }
+
+  vec![stone.saturating_mul(2024)]
+}
+
+#[allow(dead_code, reason = "debug")]
+fn serialize_stones(stones: &StoneList) -> String {
+  if stones.len() > 10 {
+    let first_ten = stones
+      .iter()
+      .map(|stone| stone.to_string())
+      .take(10)
+      .join(" ");
+    format!("{first_ten}...")
+  } else {
+    stones.iter().map(|stone| stone.to_string()).join(" ")
+  }
+}
+
+type Stone = u64;
+type StoneList = Vec<Stone>;
+type Blink = usize;
+type StoneMemo = Memo<(Stone, Blink), Stone>;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn blinks_once() {
+    assert_eq!(blink_once(0), [1]);
+    assert_eq!(blink_once(1000), [10, 0]);
+    assert_eq!(blink_once(123), [123 * 2024]);
+  }
+}
@@ -0,0 +1,158 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{collections::HashSet, fmt::Display};
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::{Point2, Vector2};
+use grind_grid::Grid;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day12>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day12;
+
+impl Solution for Day12 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 12;
+
+  type Input = Vec<Region>;
+  type Part1 = Price;
+  type Part2 = Price;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let map = input.parse::<Map>()?;
+
+    let mut regions = Vec::<Region>::new();
+
+    for position in map.positions() {
+      if regions
+        .iter()
+        .any(|region| region.positions.contains(&position))
+      {
+        continue;
+      }
+
+      regions.push(Region::new(&map, position)?);
+    }
+
+    Ok(regions)
+  }
+
+  fn part1(regions: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(
+      regions
+        .iter()
+        .map(|region| region.price_perimeter())
+        .sum::<Price>(),
+    )
+  }
+
+  fn part2(regions: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(
+      regions
+        .iter()
+        .map(|region| region.price_sides())
+        .sum::<Price>(),
+    )
+  }
+}
+
+type Plant = char;
+
+type Area = u64;
+type Perimeter = u64;
+type Side = u64;
+type Price = u64;
+
+type Map = Grid<Plant>;
+
+#[derive(Debug, Clone)]
+struct Region {
+  plant: Plant,
+  positions: HashSet<Point2>,
+  area: Area,
+  perimeter: Perimeter,
+  sides: Side,
+}
+
+impl Region {
+  fn new(map: &Map, start: Point2) -> anyhow::Result<Self> {
+    let plant = *map
+      .get(start)
+      .ok_or_else(|| anyhow::anyhow!("Region start {start} is off the map"))?;
+    let mut positions = HashSet::new();
+    positions.insert(start);
+    let mut region = Region {
+      plant,
+      positions,
+      area: 1,
+      perimeter: 0,
+      sides: 0,
+    };
+    region.fill(map, start);
+    Ok(region)
+  }
+
+  fn price_perimeter(&self) -> Price {
+    self.area.saturating_mul(self.perimeter)
+  }
+
+  fn price_sides(&self) -> Price {
+    self.area.saturating_mul(self.sides)
+  }
+
+  fn fill(&mut self, map: &Map, current: Point2) {
+    for offset in Vector2::ORTHOGONAL {
+      let next = map.offset(current, offset);
+      if let Some(next) = next {
+        if self.positions.contains(&next) {
+          continue;
+        }
+        if let Some(&plant) = map.get(next) {
+          if plant == self.plant {
+            self.area = self.area.saturating_add(1);
+            self.positions.insert(next);
+            self.fill(map, next);
+            continue;
+          }
+        }
+      }
+      self.perimeter = self.perimeter.saturating_add(1);
+      let side = if offset.y == 0 {
+        Vector2::SOUTH
+      } else {
+        Vector2::EAST
+      };
+      let side_current = map
+        .offset(current, side)
+        .and_then(|side_current| map.get(side_current))
+        .copied();
+      let side_next = next
+        .and_then(|next| map.offset(next, side))
+        .and_then(|side_next| map.get(side_next))
+        .copied();
+      if ((side_current, side_next) == (Some(self.plant), Some(self.plant)))
+        || (side_current != Some(self.plant))
+      {
+        self.sides = self.sides.saturating_add(1);
+      }
+    }
+  }
+}
+
+impl Display for Region {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "Region {{ plant: {:?}, area: {:?}, perimeter: {:?}, sides: {:?} }}",
+      self.plant, self.area, self.perimeter, self.sides
+    )
+  }
+}
@@ -0,0 +1,359 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::fmt::Display;
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_math::{solve, Rational};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day13>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day13;
+
+impl Solution for Day13 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 13;
+
+  type Input = Vec<ClawMachine>;
+  type Part1 = Price;
+  type Part2 = Price;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let miscalculated_claw_machines = input
+      .trim()
+      .split("\n\n")
+      .map(|claw_machine| {
+        ClawMachine::parse(claw_machine.trim()).ok_or_else(|| {
+          anyhow::anyhow!("Invalid claw machine:\n{}", claw_machine.trim())
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(miscalculated_claw_machines)
+  }
+
+  fn part1(
+    miscalculated_claw_machines: &Self::Input,
+  ) -> anyhow::Result<Self::Part1> {
+    Ok(miscalculated_price(miscalculated_claw_machines))
+  }
+
+  fn part2(
+    miscalculated_claw_machines: &Self::Input,
+  ) -> anyhow::Result<Self::Part2> {
+    let claw_machines = miscalculated_claw_machines
+      .iter()
+      .map(|claw_machine| claw_machine.correct())
+      .collect::<Vec<_>>();
+
+    Ok(price(&claw_machines))
+  }
+}
+
+fn price(claw_machines: &[ClawMachine]) -> Price {
+  claw_machines
+    .iter()
+    .filter_map(|claw_machine| {
+      claw_machine
+        .presses()
+        .map(|(button_a_presses, button_b_presses)| {
+          button_a_presses
+            .saturating_mul(claw_machine.button_a.price)
+            .saturating_add(
+              button_b_presses.saturating_mul(claw_machine.button_b.price),
+            )
+        })
+    })
+    .sum::<Price>()
+}
+
+fn miscalculated_price(claw_machines: &[ClawMachine]) -> Price {
+  claw_machines
+    .iter()
+    .filter_map(|claw_machine| {
+      (0..(MAX_MISCALCULATED_PRESSES + 1))
+        .cartesian_product(0..(MAX_MISCALCULATED_PRESSES + 1))
+        .filter(|(a_presses, b_presses)| {
+          claw_machine
+            .button_a
+            .offset
+            .mul(*a_presses)
+            .add(claw_machine.button_b.offset.mul(*b_presses))
+            == claw_machine.prize.position
+        })
+        .map(|(a_presses, b_presses)| {
+          a_presses
+            .saturating_mul(claw_machine.button_a.price)
+            .saturating_add(
+              b_presses.saturating_mul(claw_machine.button_b.price),
+            )
+        })
+        .min()
+    })
+    .sum::<Price>()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClawMachine {
+  button_a: Button,
+  button_b: Button,
+  prize: Prize,
+}
+
+impl ClawMachine {
+  fn parse(text: &str) -> Option<ClawMachine> {
+    text.split_once("\n").and_then(|(button_a, rest)| {
+      rest.split_once("\n").and_then(|(button_b, prize)| {
+        Button::parse(button_a.trim()).and_then(|button_a| {
+          Button::parse(button_b.trim()).and_then(|button_b| {
+            Prize::parse(prize.trim()).map(|prize| Self {
+              button_a,
+              button_b,
+              prize,
+            })
+          })
+        })
+      })
+    })
+  }
+
+  /// Presses of both buttons landing the claw on the prize when there is
+  /// exactly one way to get there.
+  fn presses(&self) -> Option<(Press, Press)> {
+    let (button_a, button_b) = (self.button_a.offset, self.button_b.offset);
+    let prize = self.prize.position;
+    let space = solve(
+      &[
+        vec![
+          Rational::from(button_a.x.value),
+          Rational::from(button_b.x.value),
+        ],
+        vec![
+          Rational::from(button_a.y.value),
+          Rational::from(button_b.y.value),
+        ],
+      ],
+      &[Rational::from(prize.x.value), Rational::from(prize.y.value)],
+    )?;
+
+    space
+      .unique()?
+      .iter()
+      .map(|presses| {
+        presses
+          .to_integer()
+          .and_then(|presses| Press::try_from(presses).ok())
+          .filter(|&presses| presses >= 0)
+      })
+      .collect::<Option<Vec<_>>>()?
+      .into_iter()
+      .collect_tuple()
+  }
+
+  fn correct(self) -> Self {
+    Self {
+      button_a: self.button_a,
+      button_b: self.button_b,
+      prize: self.prize.correct(),
+    }
+  }
+}
+
+impl Display for ClawMachine {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}\n{}\n{}", self.button_a, self.button_b, self.prize)
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Prize {
+  position: Position,
+}
+
+impl Prize {
+  fn parse(text: &str) -> Option<Self> {
+    text.split_once(": ").and_then(|(_, position)| {
+      Position::parse(position).map(|position| Self { position })
+    })
+  }
+
+  fn correct(self) -> Self {
+    Self {
+      position: self.position.correct(),
+    }
+  }
+}
+
+impl Display for Prize {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Prize: {}", self.position)
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Button {
+  id: ButtonId,
+  price: Price,
+  offset: Position,
+}
+
+impl Button {
+  fn parse(text: &str) -> Option<Self> {
+    text.split_once(": ").and_then(|(id, offset)| {
+      id.split_once(" ").and_then(|(_, id)| {
+        id.chars().next().and_then(|id| {
+          (match id {
+            'A' => Some(BUTTON_A_PRICE),
+            'B' => Some(BUTTON_B_PRICE),
+            _ => None,
+          })
+          .and_then(|price| {
+            Position::parse(offset).map(|offset| Self { id, price, offset })
+          })
+        })
+      })
+    })
+  }
+}
+
+impl Display for Button {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Button {}: {}", self.id, self.offset)
+  }
+}
+
+const BUTTON_A_PRICE: Price = 3;
+const BUTTON_B_PRICE: Price = 1;
+
+const MAX_MISCALCULATED_PRESSES: Press = 100;
+
+type ButtonId = char;
+type Press = i64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+  x: Coordinate,
+  y: Coordinate,
+}
+
+impl Position {
+  fn parse(text: &str) -> Option<Self> {
+    text.split_once(", ").and_then(|(x, y)| {
+      Coordinate::parse(y.trim())
+        .and_then(|y| Coordinate::parse(x).map(|x| Self { y, x }))
+    })
+  }
+
+  fn add(self, other: Position) -> Self {
+    Self {
+      x: self.x.add(other.x),
+      y: self.y.add(other.y),
+    }
+  }
+
+  fn mul(self, by: CoordinateValue) -> Self {
+    Self {
+      x: self.x.mul(by),
+      y: self.y.mul(by),
+    }
+  }
+
+  fn correct(self) -> Self {
+    Self {
+      x: self.x.correct(),
+      y: self.y.correct(),
+    }
+  }
+}
+
+impl Display for Position {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}, {}", self.x, self.y)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Coordinate {
+  axis: Axis,
+  value: CoordinateValue,
+  is_offset: bool,
+}
+
+impl Coordinate {
+  fn parse(text: &str) -> Option<Self> {
+    text.trim().split_once('=').map_or_else(
+      || {
+        text.split_once('+').and_then(|(axis, value)| {
+          axis.chars().next().and_then(|axis| {
+            value.trim().parse::<CoordinateValue>().ok().map(|value| {
+              Coordinate {
+                axis,
+                value,
+                is_offset: true,
+              }
+            })
+          })
+        })
+      },
+      |(axis, value)| {
+        axis.chars().next().and_then(|axis| {
+          value
+            .trim()
+            .parse::<CoordinateValue>()
+            .ok()
+            .map(|value| Coordinate {
+              axis,
+              value,
+              is_offset: false,
+            })
+        })
+      },
+    )
+  }
+
+  fn add(self, other: Coordinate) -> Coordinate {
+    Coordinate {
+      axis: self.axis,
+      value: self.value.saturating_add(other.value),
+      is_offset: self.is_offset,
+    }
+  }
+
+  fn mul(self, by: CoordinateValue) -> Coordinate {
+    Coordinate {
+      axis: self.axis,
+      value: self.value.saturating_mul(by),
+      is_offset: false,
+    }
+  }
+
+  fn correct(self) -> Self {
+    Self {
+      axis: self.axis,
+      value: self.value.saturating_add(10000000000000 as CoordinateValue),
+      is_offset: self.is_offset,
+    }
+  }
+}
+
+impl Display for Coordinate {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.is_offset {
+      write!(f, "{}+{}", self.axis, self.value)
+    } else {
+      write!(f, "{}={}", self.axis, self.value)
+    }
+  }
+}
+
+type Axis = char;
+type CoordinateValue = i64;
+type Price = i64;
@@ -0,0 +1,354 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use colored::Colorize;
+use grind_algo::Stepper;
+use grind_core::{Day, Diagnostics, Parameters, Puzzle, Solution, Year};
+use grind_geometry::{Point2, Vector2};
+use grind_grid::{Edge, SparseGrid};
+use grind_math::lcm;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day14>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day14;
+
+impl Solution for Day14 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 14;
+
+  type Input = Area;
+  type Part1 = SafetyFactor;
+  type Part2 = VelocityValue;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    Self::parse_with(input, &Parameters::default())
+  }
+
+  fn parse_with(
+    input: &str,
+    parameters: &Parameters,
+  ) -> anyhow::Result<Self::Input> {
+    let size = |name, default: Coordinate| {
+      parameters
+        .get_or(name, default)?
+        .checked_sub(1)
+        .ok_or_else(|| anyhow::anyhow!("The area {name} must be at least 1"))
+    };
+
+    Area::parse(input, size("height", HEIGHT)?, size("width", WIDTH)?)
+  }
+
+  fn part1(area: &Self::Input) -> anyhow::Result<Self::Part1> {
+    calculate_safety_factor(area.clone(), SAFETY_FACTOR_SECONDS)
+  }
+
+  fn part2(area: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(search_for_christmas_tree(area.clone())?.elapsed)
+  }
+
+  fn diagnostics(area: &Self::Input) -> anyhow::Result<Diagnostics> {
+    Diagnostics::default().with(
+      "christmas_tree",
+      search_for_christmas_tree(area.clone())?.picture()?,
+    )
+  }
+}
+
+fn calculate_safety_factor(
+  mut area: Area,
+  seconds: Second,
+) -> anyhow::Result<SafetyFactor> {
+  area.run(seconds)?;
+  Ok(area.safety_factor())
+}
+
+// NOTE: the tree only shows up when every robot sits on its own tile, which
+// has to happen before the robots are back where they have been
+fn search_for_christmas_tree(mut area: Area) -> anyhow::Result<Area> {
+  let (start, period) = area
+    .cycle()?
+    .ok_or_else(|| anyhow::anyhow!("Robots never repeat"))?;
+  for _ in 0..start.saturating_add(period) {
+    if area.counts_by_position()?.len() == area.robots.len() {
+      return Ok(area);
+    }
+    area.step()?;
+  }
+
+  Err(anyhow::anyhow!(
+    "No christmas tree before the robots repeat"
+  ))
+}
+
+type Second = usize;
+
+const SAFETY_FACTOR_SECONDS: Second = 100;
+
+const HEIGHT: Coordinate = 103;
+const WIDTH: Coordinate = 101;
+
+#[derive(Debug, Clone, Eq)]
+struct Area {
+  robots: Vec<Robot>,
+  max_y: Coordinate,
+  max_x: Coordinate,
+  elapsed: VelocityValue,
+}
+
+impl Area {
+  fn parse(
+    text: &str,
+    max_y: Coordinate,
+    max_x: Coordinate,
+  ) -> anyhow::Result<Area> {
+    let robots = text
+      .trim()
+      .split('\n')
+      .enumerate()
+      .filter(|(_, line)| !line.starts_with("//"))
+      .map(|(index, robot)| {
+        Robot::parse(robot.trim()).ok_or_else(|| {
+          anyhow::anyhow!(
+            "Invalid robot on line {}: {robot:?}",
+            index.saturating_add(1)
+          )
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Self {
+      robots,
+      max_y,
+      max_x,
+      elapsed: 0,
+    })
+  }
+
+  /// Rows around the robots with a `#` wherever robots are.
+  fn picture(&self) -> anyhow::Result<Vec<String>> {
+    Ok(
+      self
+        .counts_by_position()?
+        .map(|_, _| ROBOT_CHAR)
+        .to_string()
+        .lines()
+        .map(String::from)
+        .collect(),
+    )
+  }
+
+  /// Robots wrap around so their columns repeat after as many seconds as the
+  /// area is wide and their rows after as many as it is tall.
+  fn repeats_at(&self) -> Second {
+    lcm(self.max_x.saturating_add(1), self.max_y.saturating_add(1))
+      .unwrap_or(Second::MAX)
+  }
+
+  fn scrub(&mut self, by: VelocityValue) -> anyhow::Result<()> {
+    let width = self.max_x.saturating_add(1);
+    let height = self.max_y.saturating_add(1);
+    for robot in self.robots.iter_mut() {
+      robot.position = robot
+        .velocity
+        .checked_mul(by)
+        .and_then(|offset| {
+          AREA_EDGE.resolve(width, height, robot.position, offset)
+        })
+        .ok_or_else(|| anyhow::anyhow!("Robot {robot} ran out of the area"))?;
+    }
+    self.elapsed = self.elapsed.saturating_add(by);
+
+    Ok(())
+  }
+
+  fn safety_factor(&self) -> SafetyFactor {
+    self.counts_by_quadrant().values().fold(
+      1 as SafetyFactor,
+      |safety_factor, robot_count| -> usize {
+        safety_factor.saturating_mul(*robot_count)
+      },
+    )
+  }
+
+  fn counts_by_quadrant(&self) -> HashMap<Quadrant, Count> {
+    let mut quadrants: HashMap<Quadrant, SafetyFactor> = HashMap::new();
+    for quadrant in self
+      .robots
+      .iter()
+      .filter_map(|robot| self.quadrant(robot.position))
+    {
+      if let Some(robot_count) = quadrants.get_mut(&quadrant) {
+        *robot_count = robot_count.saturating_add(1 as SafetyFactor);
+      } else {
+        quadrants.insert(quadrant, 1 as SafetyFactor);
+      }
+    }
+    quadrants
+  }
+
+  fn quadrant(&self, position: Point2) -> Option<Quadrant> {
+    let half_x = self.max_x.saturating_div(2);
+    let half_y = self.max_y.saturating_div(2);
+
+    if position.x < half_x && position.y < half_y {
+      return Some(Quadrant::Northwest);
+    } else if position.x > half_x && position.y < half_y {
+      return Some(Quadrant::Northeast);
+    } else if position.x > half_x && position.y > half_y {
+      return Some(Quadrant::Southeast);
+    } else if position.x < half_x && position.y > half_y {
+      return Some(Quadrant::Southwest);
+    }
+
+    None
+  }
+
+  fn counts_by_position(&self) -> anyhow::Result<SparseGrid<Count>> {
+    let mut counts = SparseGrid::<Count>::new();
+    for robot in self.robots.iter() {
+      let count =
+        counts.get_or_insert_with(Vector2::try_from(robot.position)?, || 0);
+      *count = count.saturating_add(1);
+    }
+    Ok(counts)
+  }
+}
+
+impl Stepper for Area {
+  type Error = anyhow::Error;
+
+  fn step(&mut self) -> Result<bool, Self::Error> {
+    self.scrub(1)?;
+    Ok(true)
+  }
+
+  /// Robots move in straight lines so any number of steps is one jump.
+  fn run(&mut self, steps: usize) -> Result<usize, Self::Error> {
+    self.scrub(VelocityValue::try_from(steps)?)?;
+    Ok(steps)
+  }
+}
+
+impl Display for Area {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if f.alternate() {
+      let half_y = self.max_y.saturating_div(2);
+      let half_x = self.max_x.saturating_div(2);
+      let counts_by_position =
+        self.counts_by_position().map_err(|_| std::fmt::Error)?;
+      for y in 0..(self.max_y.saturating_add(1)) {
+        for x in 0..(self.max_x.saturating_add(1)) {
+          let mut to_write = ".".to_string();
+          let position = Point2 { x, y };
+          if let Some(count) = Vector2::try_from(position)
+            .ok()
+            .and_then(|position| counts_by_position.get(position))
+          {
+            to_write = count.to_string();
+          }
+          if x == half_x || y == half_y {
+            write!(f, "{}", to_write.red())?;
+          } else {
+            write!(f, "{}", to_write)?;
+          }
+        }
+
+        if y != self.max_y {
+          writeln!(f)?;
+        }
+      }
+
+      Ok(())
+    } else {
+      writeln!(
+        f,
+        "({}x{})⟳{}?{}@{}",
+        self.max_x.saturating_add(1),
+        self.max_y.saturating_add(1),
+        self.repeats_at(),
+        self.robots.len(),
+        self.elapsed
+      )?;
+
+      // for (index, robot) in self.robots.iter().enumerate() {
+      //   write!(f, "{}", robot)?;
+      //   if index != self.robots.len().saturating_sub(1) {
+      //     write!(f, "\n")?;
+      //   }
+      // }
+
+      Ok(())
+    }
+  }
+}
+
+impl PartialEq for Area {
+  fn eq(&self, other: &Self) -> bool {
+    self.robots == other.robots
+      && self.max_y == other.max_y
+      && self.max_x == other.max_x
+  }
+}
+
+type SafetyFactor = usize;
+type Count = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Robot {
+  position: Point2,
+  velocity: Vector2,
+}
+
+impl Robot {
+  fn parse(text: &str) -> Option<Self> {
+    let (position, velocity) = text.split_once(' ')?;
+    let (_, position) = position.split_once('=')?;
+    let (_, velocity) = velocity.split_once('=')?;
+    let (x, y) = parse_pair::<Coordinate>(position)?;
+    let (vx, vy) = parse_pair::<VelocityValue>(velocity)?;
+
+    Some(Self {
+      position: Point2 { x, y },
+      velocity: Vector2 { x: vx, y: vy },
+    })
+  }
+}
+
+impl Display for Robot {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "p={},{} v={},{}",
+      self.position.x, self.position.y, self.velocity.x, self.velocity.y
+    )
+  }
+}
+
+fn parse_pair<T: FromStr>(text: &str) -> Option<(T, T)> {
+  let (x, y) = text.split_once(',')?;
+  Some((x.parse().ok()?, y.parse().ok()?))
+}
+
+const AREA_EDGE: Edge<Count> = Edge::Wrap;
+
+const ROBOT_CHAR: char = '#';
+
+type Coordinate = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Quadrant {
+  Northwest,
+  Northeast,
+  Southeast,
+  Southwest,
+}
+
+type VelocityValue = isize;
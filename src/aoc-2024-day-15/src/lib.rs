@@ -0,0 +1,670 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::fmt::Display;
+
+use anyhow::Context;
+use grind_algo::Stepper;
+use grind_core::{Day, Diagnostics, Puzzle, Solution, Year};
+use grind_geometry::{Direction, Point2, Vector2};
+use grind_grid::{Edge, Grid, Tile};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day15>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day15;
+
+impl Solution for Day15 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 15;
+
+  type Input = Warehouse;
+  type Part1 = Coordinate;
+  type Part2 = Coordinate;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    Warehouse::parse(input)
+  }
+
+  fn part1(input: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(after_movements(input.clone())?.gps())
+  }
+
+  fn part2(input: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(after_movements(input.thicken()?)?.gps())
+  }
+
+  fn diagnostics(input: &Self::Input) -> anyhow::Result<Diagnostics> {
+    Diagnostics::default()
+      .with("warehouse", after_movements(input.clone())?.layout())?
+      .with(
+        "thick_warehouse",
+        after_movements(input.thicken()?)?.layout(),
+      )
+  }
+}
+
+fn after_movements(mut warehouse: Warehouse) -> anyhow::Result<Warehouse> {
+  warehouse.run_to_fixpoint()?;
+
+  Ok(warehouse)
+}
+
+#[derive(Debug, Clone)]
+struct Warehouse {
+  entities: Grid<Entity>,
+  #[allow(dead_code, reason = "used for debugging")]
+  walls: Vec<Point2>,
+  boxes: Vec<Point2>,
+  thick_boxes: Vec<ThickPosition>,
+  robot: Robot,
+  elapsed: Iteration,
+}
+
+impl Warehouse {
+  /// Rows of the map without the robot movements.
+  fn layout(&self) -> Vec<String> {
+    let warehouse = Self {
+      robot: Robot {
+        movements: Vec::new(),
+        ..self.robot.clone()
+      },
+      ..self.clone()
+    };
+
+    warehouse.to_string().lines().map(str::to_owned).collect()
+  }
+
+  fn gps(&self) -> Coordinate {
+    let box_sum = self
+      .boxes
+      .iter()
+      .map(|position| gps(*position))
+      .sum::<Coordinate>();
+
+    let thick_box_sum = self
+      .thick_boxes
+      .iter()
+      .map(|position| position.gps())
+      .sum::<Coordinate>();
+
+    box_sum.saturating_add(thick_box_sum)
+  }
+
+  fn next(&mut self) -> anyhow::Result<()> {
+    let elapsed = self.elapsed;
+    let movement = match self.robot.movements.first() {
+      Some(movement) => *movement,
+      None => return Err(anyhow::anyhow!("Movement unknown at {elapsed}")),
+    };
+    let elapsed = self.elapsed.saturating_add(1);
+    self.elapsed = elapsed;
+    self.robot.movements.remove(0);
+    let offset = movement.offset();
+
+    let next_robot_position = match self.robot.position.checked_add(offset) {
+      Some(position) => position,
+      None => return Ok(()),
+    };
+    let next_entity = match self.get(next_robot_position) {
+      Some(entity) => entity,
+      None => return Ok(()),
+    };
+
+    if next_entity == Entity::Robot {
+      return Err(anyhow::anyhow!("Robot bumped into itself at {elapsed}"));
+    }
+
+    if next_entity == Entity::None {
+      self.move_robot(next_robot_position)?;
+      return Ok(());
+    }
+
+    if next_entity == Entity::Wall {
+      return Ok(());
+    }
+
+    if next_entity == Entity::Box {
+      let mut next_and_last_box_positions = Vec::new();
+      let mut last_box_position = next_robot_position;
+      loop {
+        let next_box_position = match last_box_position.checked_add(offset) {
+          Some(position) => position,
+          None => {
+            return Ok(());
+          }
+        };
+        let next_entity = match self.get(next_box_position) {
+          Some(entity) => entity,
+          None => {
+            return Ok(());
+          }
+        };
+
+        if next_entity == Entity::Robot {
+          return Err(anyhow::anyhow!("Robot bumped into itself at {elapsed}"));
+        }
+
+        if next_entity == Entity::Wall {
+          return Ok(());
+        }
+
+        next_and_last_box_positions
+          .push((last_box_position, next_box_position));
+        if next_entity == Entity::None {
+          break;
+        }
+
+        last_box_position = next_box_position;
+      }
+
+      for (last_box_position, next_box_position) in
+        next_and_last_box_positions.into_iter().rev()
+      {
+        self.move_box(last_box_position, next_box_position)?;
+      }
+      self.move_robot(next_robot_position)?;
+    }
+
+    let last_thick_box_position = if next_entity == Entity::ThickBoxStart {
+      ThickPosition::from_start(next_robot_position).ok_or_else(|| {
+        anyhow::anyhow!(
+          "Unable to construct thick box from start {next_robot_position}"
+        )
+      })?
+    } else if next_entity == Entity::ThickBoxEnd {
+      ThickPosition::from_end(next_robot_position).ok_or_else(|| {
+        anyhow::anyhow!(
+          "Unable to construct thick box from end {next_robot_position}"
+        )
+      })?
+    } else {
+      return Ok(());
+    };
+    let mut next_and_last_thick_box_positions = Vec::new();
+    let mut last_thick_box_positions = vec![last_thick_box_position];
+    loop {
+      let mut ready_to_move = true;
+      let mut next_last_thick_box_positions = Vec::new();
+      for last_thick_box_position in last_thick_box_positions {
+        let next_thick_box_position =
+          match last_thick_box_position.checked_add(offset) {
+            Some(position) => position,
+            None => {
+              return Ok(());
+            }
+          };
+        let next_entity = match self.get_thick(next_thick_box_position) {
+          Some(entities) => entities,
+          None => {
+            return Ok(());
+          }
+        };
+
+        if next_entity.any(Entity::Robot) {
+          return Err(anyhow::anyhow!("Robot bumped into itself at {elapsed}"));
+        }
+
+        if next_entity.any(Entity::Wall) {
+          return Ok(());
+        }
+
+        next_and_last_thick_box_positions
+          .push((last_thick_box_position, next_thick_box_position));
+
+        if next_entity.any(Entity::ThickBoxStart)
+          || next_entity.any(Entity::ThickBoxEnd)
+        {
+          ready_to_move = false;
+        }
+
+        if next_entity.start == Entity::ThickBoxStart {
+          next_last_thick_box_positions.push(next_thick_box_position);
+        } else if next_entity.start == Entity::ThickBoxEnd
+          && offset != THICK_END_OFFSET
+        {
+          next_last_thick_box_positions.push(
+            ThickPosition::from_end(next_thick_box_position.start).ok_or_else(
+              || {
+                anyhow::anyhow!(
+                  "Unable to construct thick box from end {}",
+                  next_thick_box_position.start
+                )
+              },
+            )?,
+          );
+        }
+        if next_entity.end == Entity::ThickBoxEnd {
+          next_last_thick_box_positions.push(next_thick_box_position);
+        } else if next_entity.end == Entity::ThickBoxStart
+          && offset != THICK_START_OFFSET
+        {
+          next_last_thick_box_positions.push(
+            ThickPosition::from_start(next_thick_box_position.end).ok_or_else(
+              || {
+                anyhow::anyhow!(
+                  "Unable to construct thick box from start {}",
+                  next_thick_box_position.end
+                )
+              },
+            )?,
+          );
+        }
+      }
+      if ready_to_move {
+        break;
+      }
+      last_thick_box_positions = next_last_thick_box_positions;
+    }
+
+    next_and_last_thick_box_positions = next_and_last_thick_box_positions
+      .iter()
+      .unique()
+      .cloned()
+      .collect::<Vec<_>>();
+    for (last_thick_box_position, next_thick_box_position) in
+      next_and_last_thick_box_positions.into_iter().rev()
+    {
+      self.move_thick_box(last_thick_box_position, next_thick_box_position)?;
+    }
+    self.move_robot(next_robot_position)?;
+
+    Ok(())
+  }
+
+  fn thicken(&self) -> anyhow::Result<Self> {
+    let mut entities = Grid::new(
+      self.entities.width().saturating_mul(2),
+      self.entities.height(),
+      Entity::None,
+    )?
+    .with_edge(Edge::Pad(Entity::Wall));
+
+    let mut walls = self.walls.clone();
+    for position in self.walls.iter() {
+      let start_position = Point2 {
+        y: position.y,
+        x: position.x.saturating_mul(2),
+      };
+      let end_position = Point2 {
+        y: start_position.y,
+        x: start_position.x.saturating_add(1),
+      };
+      walls.push(start_position);
+      set(&mut entities, start_position, Entity::Wall);
+      set(&mut entities, end_position, Entity::Wall);
+    }
+
+    let mut thick_boxes = Vec::new();
+    for position in self.boxes.iter() {
+      let start = Point2 {
+        y: position.y,
+        x: position.x.saturating_mul(2),
+      };
+      let end = Point2 {
+        y: start.y,
+        x: start.x.saturating_add(1),
+      };
+      thick_boxes.push(ThickPosition { start, end });
+      set(&mut entities, start, Entity::ThickBoxStart);
+      set(&mut entities, end, Entity::ThickBoxEnd);
+    }
+
+    let robot = Robot {
+      position: Point2 {
+        y: self.robot.position.y,
+        x: self.robot.position.x.saturating_mul(2),
+      },
+      movements: self.robot.movements.clone(),
+    };
+    set(&mut entities, robot.position, Entity::Robot);
+
+    Ok(Self {
+      entities,
+      walls,
+      boxes: Vec::new(),
+      thick_boxes,
+      robot,
+      elapsed: self.elapsed,
+    })
+  }
+
+  fn get(&self, position: Point2) -> Option<Entity> {
+    self.entities.get(position).copied()
+  }
+
+  fn get_thick(&self, position: ThickPosition) -> Option<ThickEntity> {
+    self.get(position.start).and_then(|start| {
+      self.get(position.end).map(|end| ThickEntity { start, end })
+    })
+  }
+
+  fn move_robot(&mut self, next_position: Point2) -> anyhow::Result<()> {
+    if self.get(next_position) != Some(Entity::None) {
+      return Err(anyhow::anyhow!(
+        "Next robot position is not none at {next_position}"
+      ));
+    }
+
+    let last_position = self.robot.position;
+    set(&mut self.entities, next_position, Entity::Robot);
+    set(&mut self.entities, last_position, Entity::None);
+    self.robot.position = next_position;
+
+    Ok(())
+  }
+
+  fn move_box(
+    &mut self,
+    last_position: Point2,
+    next_position: Point2,
+  ) -> anyhow::Result<()> {
+    if self.get(next_position) != Some(Entity::None) {
+      return Err(anyhow::anyhow!(
+        "Next box position is not none at {next_position}"
+      ));
+    }
+
+    set(&mut self.entities, last_position, Entity::None);
+    set(&mut self.entities, next_position, Entity::Box);
+    for position in self.boxes.iter_mut() {
+      if *position == last_position {
+        *position = next_position;
+        break;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn move_thick_box(
+    &mut self,
+    last_position: ThickPosition,
+    next_position: ThickPosition,
+  ) -> anyhow::Result<()> {
+    let next_entity = self.get_thick(next_position).ok_or_else(|| {
+      anyhow::anyhow!("Next thick box position is outside at {next_position}")
+    })?;
+    if (next_entity.start != Entity::None
+      && next_entity.start != Entity::ThickBoxEnd)
+      || (next_entity.end != Entity::None
+        && next_entity.end != Entity::ThickBoxStart)
+    {
+      return Err(anyhow::anyhow!(
+        "Next thick box position is not none at {next_position}"
+      ));
+    }
+
+    set(&mut self.entities, last_position.start, Entity::None);
+    set(&mut self.entities, last_position.end, Entity::None);
+    set(
+      &mut self.entities,
+      next_position.start,
+      Entity::ThickBoxStart,
+    );
+    set(&mut self.entities, next_position.end, Entity::ThickBoxEnd);
+
+    for position in self.thick_boxes.iter_mut() {
+      if *position == last_position {
+        *position = next_position;
+        break;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn parse(text: &str) -> anyhow::Result<Self> {
+    let mut entities = Vec::new();
+    let mut walls = Vec::new();
+    let mut boxes = Vec::new();
+    let mut thick_boxes = Vec::new();
+    let mut robot_position = None;
+    let mut robot_movements = Vec::new();
+
+    if let Some((map, movements)) = text.split_once("\n\n") {
+      let lines = map
+        .trim()
+        .split('\n')
+        .map(|line| line.trim())
+        .collect::<Vec<_>>();
+      for (y, line) in lines.iter().enumerate() {
+        let mut line_entities = Vec::new();
+        for (x, char) in line.chars().enumerate() {
+          let entity = Entity::from_char(char)
+            .with_context(|| format!("Invalid entity at ({y}x{x})"))?;
+          line_entities.push(entity);
+          match entity {
+            Entity::Robot => robot_position = Some(Point2 { x, y }),
+            Entity::Wall => walls.push(Point2 { x, y }),
+            Entity::Box => boxes.push(Point2 { x, y }),
+            Entity::ThickBoxStart => thick_boxes.push(
+              ThickPosition::from_start(Point2 { x, y }).ok_or_else(|| {
+                anyhow::anyhow!(
+                  "Failed to construct thick box position from start ({x}x{y})"
+                )
+              })?,
+            ),
+            Entity::ThickBoxEnd | Entity::None => {}
+          }
+        }
+        entities.push(line_entities);
+      }
+
+      for (index, char) in movements
+        .trim()
+        .chars()
+        .enumerate()
+        .filter(|(_, char)| !char.is_whitespace())
+      {
+        robot_movements.push(
+          parse_direction(char)
+            .with_context(|| format!("Failed parsing direction at {index}"))?,
+        );
+      }
+    }
+    let robot_position = robot_position
+      .ok_or_else(|| anyhow::anyhow!("Robot position missing"))?;
+
+    Ok(Self {
+      entities: Grid::from_rows(entities)?.with_edge(Edge::Pad(Entity::Wall)),
+      boxes,
+      thick_boxes,
+      walls,
+      robot: Robot {
+        position: robot_position,
+        movements: robot_movements,
+      },
+      elapsed: 0 as Iteration,
+    })
+  }
+}
+
+impl Stepper for Warehouse {
+  type Error = anyhow::Error;
+
+  fn step(&mut self) -> Result<bool, Self::Error> {
+    if self.robot.movements.is_empty() {
+      return Ok(false);
+    }
+
+    self.next()?;
+    Ok(true)
+  }
+}
+
+impl Display for Warehouse {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if f.alternate() {
+      write!(
+        f,
+        "{}x{}@{}",
+        self.entities.width(),
+        self.entities.height(),
+        self.robot
+      )?;
+    }
+
+    write!(f, "{}", self.entities)?;
+
+    if !f.alternate() && !self.robot.movements.is_empty() {
+      writeln!(f)?;
+      for (index, movement) in self.robot.movements.iter().enumerate() {
+        write!(f, "{}", direction_char(*movement))?;
+        if index
+          .saturating_add(1)
+          .wrapping_rem(CHARS_PER_MOVEMENT_LINE)
+          == 0
+        {
+          writeln!(f)?;
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn set(entities: &mut Grid<Entity>, position: Point2, entity: Entity) {
+  if let Some(cell) = entities.get_mut(position) {
+    *cell = entity;
+  }
+}
+
+type Iteration = usize;
+
+const CHARS_PER_MOVEMENT_LINE: usize = 70;
+
+#[derive(Debug, Clone)]
+struct Robot {
+  position: Point2,
+  movements: Vec<Direction>,
+}
+
+impl Display for Robot {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if f.alternate() {
+      write!(f, "{}", self.position)?;
+      writeln!(f)?;
+    } else {
+      for movement in self.movements.iter() {
+        write!(f, "{}", direction_char(*movement))?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ThickEntity {
+  start: Entity,
+  end: Entity,
+}
+
+impl ThickEntity {
+  fn any(self, entity: Entity) -> bool {
+    self.start == entity || self.end == entity
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+enum Entity {
+  #[tile('@')]
+  Robot,
+  #[tile('#')]
+  Wall,
+  #[tile('O')]
+  Box,
+  #[tile('[')]
+  ThickBoxStart,
+  #[tile(']')]
+  ThickBoxEnd,
+  #[tile('.')]
+  None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ThickPosition {
+  start: Point2,
+  end: Point2,
+}
+
+impl ThickPosition {
+  fn gps(self) -> Coordinate {
+    gps(self.start)
+  }
+
+  fn from_end(end: Point2) -> Option<Self> {
+    Some(Self {
+      start: end.checked_add(THICK_START_OFFSET)?,
+      end,
+    })
+  }
+
+  fn from_start(start: Point2) -> Option<Self> {
+    Some(Self {
+      start,
+      end: start.checked_add(THICK_END_OFFSET)?,
+    })
+  }
+
+  fn checked_add(self, offset: Vector2) -> Option<Self> {
+    Some(Self {
+      start: self.start.checked_add(offset)?,
+      end: self.end.checked_add(offset)?,
+    })
+  }
+}
+
+impl Display for ThickPosition {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}->{}", self.start, self.end)
+  }
+}
+
+const THICK_END_OFFSET: Vector2 = Vector2::EAST;
+const THICK_START_OFFSET: Vector2 = Vector2::WEST;
+
+fn gps(position: Point2) -> Coordinate {
+  position
+    .y
+    .saturating_mul(Y_GPS_COORDINATE_MULTIPLIER)
+    .saturating_add(position.x.saturating_mul(X_GPS_COORDINATE_MULTIPLIER))
+}
+
+type Coordinate = usize;
+
+const Y_GPS_COORDINATE_MULTIPLIER: Coordinate = 100;
+const X_GPS_COORDINATE_MULTIPLIER: Coordinate = 1;
+
+fn parse_direction(char: char) -> anyhow::Result<Direction> {
+  match char {
+    UP_DIRECTION_CHAR => Ok(Direction::North),
+    RIGHT_DIRECTION_CHAR => Ok(Direction::East),
+    DOWN_DIRECTION_CHAR => Ok(Direction::South),
+    LEFT_DIRECTION_CHAR => Ok(Direction::West),
+    _ => Err(anyhow::anyhow!("Unknown direction character {char:?}")),
+  }
+}
+
+fn direction_char(direction: Direction) -> char {
+  match direction {
+    Direction::North => UP_DIRECTION_CHAR,
+    Direction::East => RIGHT_DIRECTION_CHAR,
+    Direction::South => DOWN_DIRECTION_CHAR,
+    Direction::West => LEFT_DIRECTION_CHAR,
+  }
+}
+
+const UP_DIRECTION_CHAR: char = '^';
+const RIGHT_DIRECTION_CHAR: char = '>';
+const DOWN_DIRECTION_CHAR: char = 'v';
+const LEFT_DIRECTION_CHAR: char = '<';
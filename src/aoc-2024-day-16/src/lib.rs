@@ -0,0 +1,155 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{cell::OnceCell, collections::HashSet};
+
+use grind_algo::{dijkstra, Graph, Search};
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::{Direction, Point2};
+use grind_grid::{Grid, Tile};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day16>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day16;
+
+impl Solution for Day16 {
+  const YEAR: Year = 2024;
+  const DAY: Day = 16;
+
+  type Input = Maze;
+  type Part1 = Cost;
+  type Part2 = Cost;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    Ok(Maze {
+      map: Map::parse(input)?,
+      search: OnceCell::new(),
+    })
+  }
+
+  fn part1(maze: &Self::Input) -> anyhow::Result<Self::Part1> {
+    maze
+      .search()?
+      .goal_cost()
+      .ok_or_else(|| anyhow::anyhow!("No path from start to end"))
+  }
+
+  fn part2(maze: &Self::Input) -> anyhow::Result<Self::Part2> {
+    let seats = maze
+      .search()?
+      .on_paths_to_goals()
+      .into_iter()
+      .map(|head| head.position)
+      .collect::<HashSet<_>>();
+
+    Ok(seats.len() as Cost)
+  }
+}
+
+/// Map along with its cheapest paths.
+// NOTE: both parts look at the same cheapest paths so whichever part runs
+// first searches for them and the other one reuses them
+#[derive(Debug)]
+struct Maze {
+  map: Map,
+  search: OnceCell<Search<Head, Cost>>,
+}
+
+impl Maze {
+  fn search(&self) -> anyhow::Result<&Search<Head, Cost>> {
+    let search = self.search.get_or_init(|| self.map.search());
+    if search.goals().is_empty() {
+      return Err(anyhow::anyhow!("No path from start to end"));
+    }
+
+    Ok(search)
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+  entities: Grid<Entity>,
+  start: Point2,
+  end: Point2,
+}
+
+impl Map {
+  fn parse(text: &str) -> anyhow::Result<Self> {
+    let entities = text.parse::<Grid<Entity>>()?;
+
+    let start = entities
+      .find(|&entity| entity == Entity::Start)
+      .ok_or_else(|| anyhow::anyhow!("Map start missing"))?;
+    let end = entities
+      .find(|&entity| entity == Entity::End)
+      .ok_or_else(|| anyhow::anyhow!("Map end missing"))?;
+
+    Ok(Self {
+      entities,
+      start,
+      end,
+    })
+  }
+
+  fn search(&self) -> Search<Head, Cost> {
+    let start = Head {
+      position: self.start,
+      direction: START_DIRECTION,
+    };
+    dijkstra(self, [start], |head| head.position == self.end)
+  }
+}
+
+impl Graph for Map {
+  type State = Head;
+  type Cost = Cost;
+
+  fn successors(&self, head: &Head) -> impl Iterator<Item = (Head, Cost)> {
+    let step = head
+      .position
+      .checked_add(head.direction.offset())
+      .filter(|&position| {
+        self
+          .entities
+          .get(position)
+          .is_some_and(|&entity| entity != Entity::Wall)
+      })
+      .map(|position| (Head { position, ..*head }, STEP_COST));
+    let turns = [head.direction.turn_left(), head.direction.turn_right()]
+      .map(|direction| (Head { direction, ..*head }, TURN_COST));
+
+    step.into_iter().chain(turns)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Head {
+  direction: Direction,
+  position: Point2,
+}
+
+const START_DIRECTION: Direction = Direction::East;
+
+const STEP_COST: Cost = 1;
+const TURN_COST: Cost = 1000;
+
+type Cost = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+enum Entity {
+  #[tile('S')]
+  Start,
+  #[tile('E')]
+  End,
+  #[tile('#')]
+  Wall,
+  #[tile('.')]
+  Space,
+}
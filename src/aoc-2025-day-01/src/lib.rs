@@ -0,0 +1,222 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::fmt::Display;
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_math::Modular;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day01>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day01;
+
+impl Solution for Day01 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 1;
+
+  type Input = Vec<Rotation>;
+  type Part1 = u32;
+  type Part2 = u32;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let rotations = input
+      .trim()
+      .split("\n")
+      .enumerate()
+      .map(|(index, rotation)| {
+        Rotation::parse(rotation).with_context(|| {
+          format!(
+            "Invalid rotation on line {}: {rotation:?}",
+            index.saturating_add(1)
+          )
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(rotations)
+  }
+
+  fn part1(rotations: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(
+      rotations
+        .iter()
+        .cloned()
+        .try_fold(Dial::new()?, Dial::rotate)?
+        .zeroes,
+    )
+  }
+
+  fn part2(rotations: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(
+      rotations
+        .iter()
+        .cloned()
+        .try_fold(Dial::new()?, Dial::rotate_click)?
+        .zeroes,
+    )
+  }
+}
+
+const DIAL_SIZE: u32 = 100;
+const DIAL_START: u32 = 50;
+
+#[derive(Debug, Clone, Copy)]
+struct Dial {
+  position: Modular<u32>,
+  zeroes: u32,
+}
+
+impl Dial {
+  fn new() -> anyhow::Result<Self> {
+    Ok(Self {
+      position: Modular::new(DIAL_START, DIAL_SIZE)
+        .ok_or_else(|| anyhow::anyhow!("Dial has no positions"))?,
+      zeroes: 0,
+    })
+  }
+
+  fn rotate(self, rotation: Rotation) -> anyhow::Result<Self> {
+    let position = match rotation {
+      Rotation::Left(value) => self.position.checked_sub(value),
+      Rotation::Right(value) => self.position.checked_add(value),
+    }
+    .ok_or_else(|| anyhow::anyhow!("Rotating {self} by {rotation} failed"))?;
+
+    let zeroes = if position.value() == 0 {
+      self.zeroes.saturating_add(1)
+    } else {
+      self.zeroes
+    };
+
+    Ok(Self { position, zeroes })
+  }
+
+  fn rotate_click(self, rotation: Rotation) -> anyhow::Result<Self> {
+    // NOTE: turning left onto zero is turning right onto zero on a mirrored
+    // dial so both count zeroes as wraps forward
+    let (position, zeroes) = match rotation {
+      Rotation::Left(value) => self
+        .position
+        .checked_neg()
+        .and_then(|mirrored| mirrored.forward(value))
+        .and_then(|(mirrored, zeroes)| Some((mirrored.checked_neg()?, zeroes))),
+      Rotation::Right(value) => self.position.forward(value),
+    }
+    .ok_or_else(|| anyhow::anyhow!("Rotating {self} by {rotation} failed"))?;
+
+    Ok(Self {
+      position,
+      zeroes: self.zeroes.saturating_add(zeroes),
+    })
+  }
+}
+
+impl Display for Dial {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}:{}", self.position.value(), self.zeroes)
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Rotation {
+  Left(u32),
+  Right(u32),
+}
+
+impl Rotation {
+  fn parse(text: &str) -> anyhow::Result<Self> {
+    let text = text.trim();
+    if let Some(value) = text.strip_prefix('L') {
+      Ok(Rotation::Left(value.parse::<u32>()?))
+    } else if let Some(value) = text.strip_prefix('R') {
+      Ok(Rotation::Right(value.parse::<u32>()?))
+    } else {
+      Err(anyhow::anyhow!("Invalid rotation {text:?}"))
+    }
+  }
+}
+
+impl Display for Rotation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Rotation::Left(value) => write!(f, "L{}", value),
+      Rotation::Right(value) => write!(f, "R{}", value),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  fn click(dial: Dial, rotation: Rotation) -> Option<Dial> {
+    let (clicks, step) = match rotation {
+      Rotation::Left(value) => (value, DIAL_SIZE.checked_sub(1)?),
+      Rotation::Right(value) => (value, 1),
+    };
+    (0..clicks).try_fold(dial, |dial, _| {
+      let position = dial.position.checked_add(step)?;
+      let zeroes = if position.value() == 0 {
+        dial.zeroes.saturating_add(1)
+      } else {
+        dial.zeroes
+      };
+      Some(Dial { position, zeroes })
+    })
+  }
+
+  fn parse_error(input: &str) -> Option<String> {
+    Day01::parse(input).err().map(|err| format!("{err:#}"))
+  }
+
+  #[test]
+  fn rejects_malformed_rotations() {
+    assert_eq!(
+      parse_error("R5\n\nL3\n").as_deref(),
+      Some("Invalid rotation on line 2: \"\": Invalid rotation \"\"")
+    );
+    assert_eq!(
+      parse_error("").as_deref(),
+      Some("Invalid rotation on line 1: \"\": Invalid rotation \"\"")
+    );
+    assert_eq!(
+      parse_error("R5\nÜ3").as_deref(),
+      Some("Invalid rotation on line 2: \"Ü3\": Invalid rotation \"Ü3\"")
+    );
+    assert!(parse_error("R5\nLx").is_some());
+    assert!(parse_error("R5\nL3\n").is_none());
+  }
+
+  proptest! {
+    #[test]
+    fn clicks_like_stepping(
+      rotations in prop::collection::vec(
+        prop_oneof![
+          (0u32..300).prop_map(Rotation::Left),
+          (0u32..300).prop_map(Rotation::Right),
+        ],
+        0..20,
+      ),
+    ) {
+      let mut dial = Dial::new().ok();
+      let mut clicked = dial;
+      for rotation in rotations {
+        dial = dial.and_then(|dial| dial.rotate_click(rotation).ok());
+        clicked = clicked.and_then(|clicked| click(clicked, rotation));
+        prop_assert_eq!(
+          dial.map(|dial| dial.to_string()),
+          clicked.map(|clicked| clicked.to_string())
+        );
+      }
+    }
+  }
+}
@@ -0,0 +1,119 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_math::{pow10, RangeSet};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day02;
+
+impl Solution for Day02 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 2;
+
+  type Input = RangeSet<IdNum>;
+  type Part1 = IdNum;
+  type Part2 = IdNum;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let ranges = input
+      .trim()
+      .split(',')
+      .map(|range| {
+        parse_range(range)
+          .ok_or_else(|| anyhow::anyhow!("Invalid id range {:?}", range.trim()))
+      })
+      .collect::<anyhow::Result<RangeSet<_>>>()?;
+
+    Ok(ranges)
+  }
+
+  fn part1(ranges: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(invalid_sum(ranges, |repeats| repeats == 2))
+  }
+
+  fn part2(ranges: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(invalid_sum(ranges, |repeats| repeats >= 2))
+  }
+}
+
+fn parse_range(text: &str) -> Option<RangeInclusive<IdNum>> {
+  let (start, end) = text.trim().split('-').collect_tuple()?;
+  Some(start.parse::<IdNum>().ok()?..=end.parse::<IdNum>().ok()?)
+}
+
+/// Sums ids in `ranges` made of a block of digits repeated a number of times
+/// `repeats` accepts.
+fn invalid_sum(
+  ranges: &RangeSet<IdNum>,
+  repeats: impl Fn(IdExp) -> bool,
+) -> IdNum {
+  let mut invalid = BTreeSet::new();
+
+  // NOTE: instead of checking every id in a range this only goes over the
+  // blocks whose repetition lands in the range
+  for range in ranges.ranges() {
+    for digits in ID_EXP_MIN..=ID_EXP_MAX {
+      for block in 1..=digits.saturating_div(2) {
+        let Some(times) = digits.checked_div(block) else {
+          continue;
+        };
+        if digits.checked_rem(block) != Some(0) || !repeats(times) {
+          continue;
+        }
+        let Some(multiplier) =
+          repeated_ones(block, times).filter(|&multiplier| multiplier != 0)
+        else {
+          continue;
+        };
+
+        let Some(largest) = pow10::<IdNum>(block) else {
+          continue;
+        };
+        let largest = largest.saturating_sub(1);
+        let smallest = pow10(block.saturating_sub(1)).unwrap_or(ID_NUM_0);
+        let low = range.start().div_ceil(multiplier).max(smallest);
+        let Some(high) = range.end().checked_div(multiplier) else {
+          continue;
+        };
+        let high = high.min(largest);
+        invalid.extend(
+          (low..=high).filter_map(|value| value.checked_mul(multiplier)),
+        );
+      }
+    }
+  }
+
+  invalid
+    .into_iter()
+    .fold(ID_NUM_0, |sum, id| sum.saturating_add(id))
+}
+
+/// Number that repeats a block of `block` digits `times` times when
+/// multiplied with it, like 1001 for two blocks of three digits.
+fn repeated_ones(block: IdExp, times: IdExp) -> Option<IdNum> {
+  let shift = pow10::<IdNum>(block)?;
+  (0..times).try_fold(ID_NUM_0, |multiplier, _| {
+    multiplier.checked_mul(shift)?.checked_add(1)
+  })
+}
+
+type IdNum = u64;
+type IdExp = u32;
+
+const ID_NUM_0: IdNum = 0;
+
+const ID_EXP_MIN: IdExp = 1;
+// NOTE: max u64 is 18_446_744_073_709_551_615u64 which has 20 digits
+const ID_EXP_MAX: IdExp = 20;
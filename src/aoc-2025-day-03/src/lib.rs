@@ -0,0 +1,179 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+use itertools::Itertools;
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day03>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day03;
+
+impl Solution for Day03 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 3;
+
+  type Input = Vec<Bank>;
+  type Part1 = Joltage;
+  type Part2 = Joltage;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let banks = input
+      .trim()
+      .split('\n')
+      .enumerate()
+      .map(|(index, line)| {
+        line.parse::<Bank>().with_context(|| {
+          format!("invalid bank on line {}: {line:?}", index.saturating_add(1))
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(banks)
+  }
+
+  fn part1(banks: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(max_joltage(banks, 2))
+  }
+
+  fn part2(banks: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(max_joltage(banks, 12))
+  }
+}
+
+fn max_joltage(banks: &[Bank], batteries: usize) -> Joltage {
+  banks.iter().fold(0 as Joltage, |sum, bank| {
+    sum.saturating_add(bank.max_joltage(batteries))
+  })
+}
+
+#[derive(Debug, Clone)]
+struct Bank {
+  batteries: Vec<Battery>,
+}
+
+impl Bank {
+  fn max_joltage(&self, mut num: usize) -> Joltage {
+    let mut max_index = Option::<usize>::None;
+    let mut sum = 0 as Joltage;
+    let mut pow =
+      (10 as Joltage).saturating_pow(num.saturating_sub(1) as JoltageExp);
+
+    while num > 0 {
+      let Some((next_max_index, battery)) =
+        (max_index.map(|index| index.saturating_add(1)).unwrap_or(0)
+          ..=self.batteries.len().saturating_sub(num))
+          .rev()
+          .filter_map(|index| Some((index, self.batteries.get(index)?)))
+          .max_by_key(|(_, battery)| **battery)
+      else {
+        return 0 as Joltage;
+      };
+
+      sum = sum.saturating_add(battery.joltage.saturating_mul(pow));
+      pow = pow.saturating_div(10);
+      max_index = Some(next_max_index);
+      num = num.saturating_sub(1);
+    }
+
+    sum
+  }
+}
+
+impl Display for Bank {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.batteries.iter().join(""))
+  }
+}
+
+impl FromStr for Bank {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let batteries = s
+      .trim()
+      .chars()
+      .map(Battery::try_from)
+      .collect::<Result<Vec<_>, _>>()?;
+    if batteries.is_empty() {
+      return Err(anyhow::anyhow!("bank has no batteries"));
+    }
+
+    Ok(Self { batteries })
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Battery {
+  joltage: Joltage,
+}
+
+impl Display for Battery {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.joltage)
+  }
+}
+
+impl FromStr for Battery {
+  type Err = ParseIntError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let joltage = s.trim().parse::<Joltage>()?;
+    Ok(Self { joltage })
+  }
+}
+
+impl TryFrom<char> for Battery {
+  type Error = anyhow::Error;
+
+  fn try_from(value: char) -> Result<Self, Self::Error> {
+    let joltage = value
+      .to_digit(10)
+      .ok_or_else(|| anyhow::anyhow!("not a number"))?;
+    Ok(Self {
+      joltage: joltage as Joltage,
+    })
+  }
+}
+
+type Joltage = u64;
+type JoltageExp = u32;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse_error(input: &str) -> Option<String> {
+    Day03::parse(input).err().map(|err| format!("{err:#}"))
+  }
+
+  #[test]
+  fn rejects_empty_banks() {
+    assert_eq!(
+      parse_error("").as_deref(),
+      Some("invalid bank on line 1: \"\": bank has no batteries")
+    );
+    assert_eq!(
+      parse_error("987\n\n123\n").as_deref(),
+      Some("invalid bank on line 2: \"\": bank has no batteries")
+    );
+    assert_eq!(
+      parse_error("987\n12x").as_deref(),
+      Some("invalid bank on line 2: \"12x\": not a number")
+    );
+    assert_eq!(
+      Day03::parse("81\n9")
+        .ok()
+        .map(|banks| max_joltage(&banks, 2)),
+      Some(81)
+    );
+  }
+}
@@ -0,0 +1,115 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+use grind_algo::Stepper;
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_grid::{BitGrid, Grid, Tile};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day04>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day04;
+
+impl Solution for Day04 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 4;
+
+  type Input = PrintingDepartment;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let printing_department = input.parse::<PrintingDepartment>()?;
+
+    Ok(printing_department)
+  }
+
+  fn part1(printing_department: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(printing_department.accessible_by_forklift_count())
+  }
+
+  fn part2(printing_department: &Self::Input) -> anyhow::Result<Self::Part2> {
+    printing_department.accessible_by_forklift_repeating_count()
+  }
+}
+
+#[derive(Debug, Clone)]
+struct PrintingDepartment {
+  tiles: Grid<PrintingDepartmentTile>,
+  paper: BitGrid,
+}
+
+impl PrintingDepartment {
+  fn accessible_by_forklift_repeating_count(&self) -> anyhow::Result<usize> {
+    let mut forklift = Forklift {
+      paper: self.paper.clone(),
+      removed: 0,
+    };
+    forklift.run_to_fixpoint()?;
+    Ok(forklift.removed)
+  }
+
+  fn accessible_by_forklift_count(&self) -> usize {
+    accessible_by_forklift(&self.paper).count()
+  }
+}
+
+/// Forklift taking away every accessible roll of paper at once each step.
+#[derive(Debug, Clone)]
+struct Forklift {
+  paper: BitGrid,
+  removed: usize,
+}
+
+impl Stepper for Forklift {
+  type Error = Infallible;
+
+  fn step(&mut self) -> Result<bool, Self::Error> {
+    let accessible = accessible_by_forklift(&self.paper);
+    if accessible.is_empty() {
+      return Ok(false);
+    }
+    self.removed = self.removed.saturating_add(accessible.count());
+    self.paper.difference_with(&accessible);
+    Ok(true)
+  }
+}
+
+fn accessible_by_forklift(paper: &BitGrid) -> BitGrid {
+  let mut accessible = paper.clone();
+  accessible.difference_with(&paper.neighbours8_at_least(4));
+  accessible
+}
+
+impl Display for PrintingDepartment {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.tiles)
+  }
+}
+
+impl FromStr for PrintingDepartment {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let tiles = s.parse::<Grid<PrintingDepartmentTile>>()?;
+    let paper = tiles.mask(|&tile| tile == PrintingDepartmentTile::Paper)?;
+
+    Ok(Self { tiles, paper })
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Tile)]
+enum PrintingDepartmentTile {
+  #[tile('.')]
+  Empty,
+  #[tile('@')]
+  Paper,
+}
@@ -0,0 +1,118 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_math::RangeSet;
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day05>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day05;
+
+impl Solution for Day05 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 5;
+
+  type Input = Database;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let database = input.parse::<Database>()?;
+
+    Ok(database)
+  }
+
+  fn part1(database: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(database.count_fresh_ingredients())
+  }
+
+  fn part2(database: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(database.count_possible_fresh_ingredients())
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Database {
+  ranges: RangeSet<IngredientId>,
+  ingredients: Vec<IngredientId>,
+}
+
+impl Database {
+  fn count_possible_fresh_ingredients(&self) -> usize {
+    self.ranges.covered() as usize
+  }
+
+  fn count_fresh_ingredients(&self) -> usize {
+    self
+      .ingredients
+      .iter()
+      .filter(|&&ingredient| self.ranges.contains(ingredient))
+      .count()
+  }
+}
+
+impl Display for Database {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}\n\n{}",
+      self
+        .ranges
+        .ranges()
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .join("\n"),
+      self.ingredients.iter().join("\n"),
+    )
+  }
+}
+
+impl FromStr for Database {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let Some((ranges, ingredients)) = s.trim().split("\n\n").collect_tuple()
+    else {
+      return Err(anyhow::anyhow!("Invalid database {s}"));
+    };
+
+    let ranges = ranges
+      .split("\n")
+      .map(parse_range)
+      .collect::<Result<RangeSet<_>, _>>()?;
+    let ingredients = ingredients
+      .split("\n")
+      .map(|ingredient| ingredient.trim().parse::<IngredientId>())
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|err| anyhow::anyhow!("Invalid ingredient id: {err}"))?;
+
+    Ok(Self {
+      ranges,
+      ingredients,
+    })
+  }
+}
+
+fn parse_range(s: &str) -> anyhow::Result<RangeInclusive<IngredientId>> {
+  let Some((Ok(start), Ok(end))) = s
+    .trim()
+    .split('-')
+    .map(|id| id.parse::<IngredientId>())
+    .collect_tuple()
+  else {
+    return Err(anyhow::anyhow!("Invalid range {s}"));
+  };
+
+  Ok(start..=end)
+}
+
+type IngredientId = u64;
@@ -0,0 +1,346 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day06>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day06;
+
+impl Solution for Day06 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 6;
+
+  type Input = (Worksheet, CorrectedWorksheet);
+  type Part1 = Operand;
+  type Part2 = Operand;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    Ok((
+      input.parse::<Worksheet>()?,
+      input.parse::<CorrectedWorksheet>()?,
+    ))
+  }
+
+  fn part1((worksheet, _): &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(worksheet.solve())
+  }
+
+  fn part2(
+    (_, corrected_worksheet): &Self::Input,
+  ) -> anyhow::Result<Self::Part2> {
+    Ok(corrected_worksheet.solve())
+  }
+}
+
+#[derive(Debug, Clone)]
+struct CorrectedWorksheet {
+  problems: Vec<Problem>,
+}
+
+impl CorrectedWorksheet {
+  fn solve(&self) -> Operand {
+    self.problems.iter().fold(0 as Operand, |sum, problem| {
+      sum.saturating_add(problem.solve())
+    })
+  }
+}
+
+impl Display for CorrectedWorksheet {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write_problems(f, &self.problems)
+  }
+}
+
+impl FromStr for CorrectedWorksheet {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let lines = numbered_lines(s)
+      .map(|(line, text)| (line, text.trim_end().chars().collect::<Vec<_>>()))
+      .collect::<Vec<_>>();
+    let Some(((operations_line, operations), rows)) = lines.split_last() else {
+      return Err(anyhow::anyhow!("worksheet cannot be empty"));
+    };
+    if rows.is_empty() {
+      return Err(anyhow::anyhow!(
+        "missing operands above line {operations_line}"
+      ));
+    }
+
+    // NOTE: every problem starts at its operation and ends a blank column
+    // before the next one
+    let starts = operations
+      .iter()
+      .enumerate()
+      .filter(|(_, char)| !char.is_whitespace())
+      .map(|(column, _)| column)
+      .collect::<Vec<_>>();
+    let end = lines
+      .iter()
+      .map(|(_, text)| text.len())
+      .max()
+      .unwrap_or_default();
+
+    let problems = starts
+      .iter()
+      .enumerate()
+      .map(|(index, &start)| {
+        let stop = starts
+          .get(index.saturating_add(1))
+          .map_or(end, |next| next.saturating_sub(1));
+        let operation = operations
+          .get(start)
+          .copied()
+          .map(Operation::try_from)
+          .ok_or_else(|| {
+            anyhow::anyhow!("missing operation on line {operations_line}")
+          })?
+          .with_context(|| {
+            format!("invalid operation on line {operations_line}")
+          })?;
+        let operands = (start..stop)
+          .map(|column| corrected_operand(rows, column, *operations_line))
+          .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Problem {
+          operands,
+          operation,
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Self { problems })
+  }
+}
+
+/// Operand written top to bottom in `column` of the rows above the
+/// operations.
+fn corrected_operand(
+  rows: &[(usize, Vec<char>)],
+  column: usize,
+  operations_line: usize,
+) -> anyhow::Result<Operand> {
+  let mut digits = String::new();
+  for (line, text) in rows {
+    match text.get(column) {
+      Some(char) if char.is_ascii_digit() => digits.push(*char),
+      Some(char) if !char.is_whitespace() => {
+        return Err(anyhow::anyhow!(
+          "invalid digit {char:?} on line {line} column {}",
+          column.saturating_add(1)
+        ));
+      }
+      _ => {}
+    }
+  }
+
+  if digits.is_empty() {
+    return Err(anyhow::anyhow!(
+      "missing operand in column {} above line {operations_line}",
+      column.saturating_add(1)
+    ));
+  }
+  Ok(digits.parse::<Operand>()?)
+}
+
+#[derive(Debug, Clone)]
+struct Worksheet {
+  problems: Vec<Problem>,
+}
+
+impl Worksheet {
+  fn solve(&self) -> Operand {
+    self.problems.iter().fold(0 as Operand, |sum, problem| {
+      sum.saturating_add(problem.solve())
+    })
+  }
+}
+
+impl Display for Worksheet {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write_problems(f, &self.problems)
+  }
+}
+
+impl FromStr for Worksheet {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let lines = numbered_lines(s)
+      .map(|(line, text)| (line, text.split_whitespace().collect::<Vec<_>>()))
+      .collect::<Vec<_>>();
+    let Some(((operations_line, operations), rows)) = lines.split_last() else {
+      return Err(anyhow::anyhow!("worksheet cannot be empty"));
+    };
+    if rows.is_empty() {
+      return Err(anyhow::anyhow!(
+        "missing operands above line {operations_line}"
+      ));
+    }
+
+    let problems = operations
+      .iter()
+      .enumerate()
+      .map(|(index, operation)| {
+        let operands = rows
+          .iter()
+          .map(|(line, items)| {
+            items
+              .get(index)
+              .ok_or_else(|| {
+                anyhow::anyhow!(
+                  "missing operand {} on line {line}",
+                  index.saturating_add(1)
+                )
+              })?
+              .parse::<Operand>()
+              .with_context(|| format!("invalid operand on line {line}"))
+          })
+          .collect::<anyhow::Result<Vec<_>>>()?;
+        let operation = operation.parse::<Operation>().with_context(|| {
+          format!("invalid operation on line {operations_line}")
+        })?;
+
+        Ok(Problem {
+          operands,
+          operation,
+        })
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Self { problems })
+  }
+}
+
+/// Non-empty lines of `s` along with their number counting from one.
+fn numbered_lines(s: &str) -> impl Iterator<Item = (usize, &str)> {
+  s.split('\n')
+    .enumerate()
+    .filter(|(_, text)| !text.trim().is_empty())
+    .map(|(index, text)| (index.saturating_add(1), text))
+}
+
+/// Operands in rows and the operations below them, right aligned.
+fn write_problems(
+  f: &mut std::fmt::Formatter<'_>,
+  problems: &[Problem],
+) -> std::fmt::Result {
+  let max_width = problems
+    .iter()
+    .flat_map(|problem| &problem.operands)
+    .map(|operand| operand.to_string().len())
+    .max()
+    .unwrap_or(1);
+  let max_operands = problems
+    .iter()
+    .map(|problem| problem.operands.len())
+    .max()
+    .unwrap_or_default();
+
+  for row in 0..max_operands {
+    let operands = problems.iter().map(|problem| {
+      format!(
+        "{:>max_width$}",
+        problem.operands.get(row).copied().unwrap_or_default()
+      )
+    });
+    writeln!(f, "{}", operands.format(" "))?;
+  }
+  let operations = problems
+    .iter()
+    .map(|problem| format!("{:>max_width$}", problem.operation));
+  write!(f, "{}", operations.format(" "))
+}
+
+#[derive(Debug, Clone)]
+struct Problem {
+  operands: Vec<Operand>,
+  operation: Operation,
+}
+
+impl Problem {
+  fn solve(&self) -> Operand {
+    self
+      .operands
+      .iter()
+      .fold(self.operation.initial(), |acc, &next| {
+        self.operation.apply(acc, next)
+      })
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+  Sum,
+  Product,
+}
+
+impl Operation {
+  fn initial(self) -> Operand {
+    match self {
+      Operation::Sum => 0,
+      Operation::Product => 1,
+    }
+  }
+
+  fn apply(self, lhs: Operand, rhs: Operand) -> Operand {
+    match self {
+      Operation::Sum => lhs.saturating_add(rhs),
+      Operation::Product => lhs.saturating_mul(rhs),
+    }
+  }
+}
+
+impl Display for Operation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let symbol = match self {
+      Operation::Sum => SUM_OPERATION_STR,
+      Operation::Product => PRODUCT_OPERATION_STR,
+    };
+
+    f.pad(symbol)
+  }
+}
+
+impl FromStr for Operation {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.chars().nth(0) {
+      Some(SUM_OPERATION_CHAR) => Ok(Operation::Sum),
+      Some(PRODUCT_OPERATION_CHAR) => Ok(Operation::Product),
+      _ => Err(anyhow::anyhow!("invalid operation {s}")),
+    }
+  }
+}
+
+impl TryFrom<char> for Operation {
+  type Error = anyhow::Error;
+
+  fn try_from(value: char) -> Result<Self, Self::Error> {
+    match value {
+      SUM_OPERATION_CHAR => Ok(Operation::Sum),
+      PRODUCT_OPERATION_CHAR => Ok(Operation::Product),
+      _ => Err(anyhow::anyhow!("invalid operation {value}")),
+    }
+  }
+}
+
+const SUM_OPERATION_CHAR: char = '+';
+const PRODUCT_OPERATION_CHAR: char = '*';
+
+const SUM_OPERATION_STR: &str = "+";
+const PRODUCT_OPERATION_STR: &str = "*";
+
+type Operand = u64;
@@ -0,0 +1,204 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use grind_algo::{History, Memo, Stepper};
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::{Point2, Vector2};
+use grind_grid::{Grid, Tile};
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day07>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day07;
+
+impl Solution for Day07 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 7;
+
+  type Input = TachyonManifoldState;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    input.parse::<TachyonManifoldState>()
+  }
+
+  fn part1(state: &Self::Input) -> anyhow::Result<Self::Part1> {
+    let mut history = History::new(TachyonManifold::from(state.clone()));
+    history.run_to_fixpoint()?;
+
+    Ok(
+      history
+        .states()
+        .map(|tachyon_manifold| tachyon_manifold.splits)
+        .fold(0_usize, |sum, splits| sum.saturating_add(splits)),
+    )
+  }
+
+  fn part2(state: &Self::Input) -> anyhow::Result<Self::Part2> {
+    let start = state
+      .tiles
+      .find(|tile| matches!(tile, TachyonManifoldTile::Start))
+      .ok_or_else(|| anyhow::anyhow!("Tachyon manifold has no start"))?;
+
+    Ok(state.timelines(start, &mut Memo::new()))
+  }
+}
+
+/// Tachyon manifold along with the beams and splits its last step added.
+#[derive(Debug, Clone)]
+struct TachyonManifold {
+  state: TachyonManifoldState,
+  splits: usize,
+  beams: usize,
+}
+
+impl Stepper for TachyonManifold {
+  type Error = Infallible;
+
+  fn step(&mut self) -> Result<bool, Self::Error> {
+    let next = self.state.step();
+    if next.beams == 0 {
+      return Ok(false);
+    }
+    *self = next;
+    Ok(true)
+  }
+}
+
+impl Display for TachyonManifold {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "Beams: {}\nSplits: {}\nState:\n{}",
+      self.beams, self.splits, self.state
+    )
+  }
+}
+
+impl From<TachyonManifoldState> for TachyonManifold {
+  fn from(state: TachyonManifoldState) -> Self {
+    Self {
+      state,
+      splits: 0,
+      beams: 0,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+struct TachyonManifoldState {
+  tiles: Grid<TachyonManifoldTile>,
+}
+
+impl TachyonManifoldState {
+  fn step(&self) -> TachyonManifold {
+    let mut splits = 0_usize;
+    let mut beams = 0_usize;
+    let tiles = self.tiles.map(|position, &tile| {
+      if !matches!(tile, TachyonManifoldTile::Empty) {
+        return tile;
+      }
+
+      let at = |offset: Vector2| {
+        self
+          .tiles
+          .offset(position, offset)
+          .and_then(|position| self.tiles.get(position))
+          .copied()
+      };
+      let beam_at = |offset: Vector2| {
+        matches!(
+          at(offset),
+          Some(TachyonManifoldTile::Start | TachyonManifoldTile::Beam)
+        )
+      };
+      let splitter_at = |offset: Vector2| {
+        matches!(at(offset), Some(TachyonManifoldTile::Splitter))
+      };
+
+      if beam_at(Vector2::NORTH_EAST) && splitter_at(Vector2::EAST) {
+        beams = beams.saturating_add(1);
+        splits = splits.saturating_add(1);
+        return TachyonManifoldTile::Beam;
+      }
+
+      if beam_at(Vector2::NORTH_WEST) && splitter_at(Vector2::WEST) {
+        beams = beams.saturating_add(1);
+        if position.x == 1 {
+          splits = splits.saturating_add(1);
+        }
+        return TachyonManifoldTile::Beam;
+      }
+
+      if beam_at(Vector2::NORTH) {
+        beams = beams.saturating_add(1);
+        return TachyonManifoldTile::Beam;
+      }
+
+      TachyonManifoldTile::Empty
+    });
+    TachyonManifold {
+      state: TachyonManifoldState { tiles },
+      splits,
+      beams,
+    }
+  }
+
+  /// How many timelines a beam passing through `position` ends up in.
+  fn timelines(
+    &self,
+    position: Point2,
+    memo: &mut Memo<Point2, usize>,
+  ) -> usize {
+    let beam = |position: Point2, offset: Vector2, memo: &mut Memo<_, _>| {
+      self
+        .tiles
+        .offset(position, offset)
+        .map_or(1, |next| self.timelines(next, memo))
+    };
+
+    memo.get_or_insert_with(position, |memo| match self.tiles.get(position) {
+      Some(TachyonManifoldTile::Splitter) => beam(
+        position,
+        Vector2::WEST,
+        memo,
+      )
+      .saturating_add(beam(position, Vector2::EAST, memo)),
+      _ => beam(position, Vector2::SOUTH, memo),
+    })
+  }
+}
+
+impl Display for TachyonManifoldState {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.tiles)
+  }
+}
+
+impl FromStr for TachyonManifoldState {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(Self { tiles: s.parse()? })
+  }
+}
+
+#[derive(Debug, Clone, Copy, Tile)]
+enum TachyonManifoldTile {
+  #[tile('.')]
+  Empty,
+  #[tile('^')]
+  Splitter,
+  #[tile('S')]
+  Start,
+  #[tile('|')]
+  Beam,
+}
@@ -0,0 +1,372 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use grind_algo::UnionFind;
+use grind_core::{Day, Diagnostics, Parameters, Puzzle, Year};
+use grind_geometry::Point3;
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day08>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day08;
+
+impl grind_core::Solution for Day08 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 8;
+
+  type Input = Playground;
+  type Part1 = Solution;
+  type Part2 = Solution;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let playground = input.parse::<Playground>()?;
+
+    Ok(playground)
+  }
+
+  fn parse_with(
+    input: &str,
+    parameters: &Parameters,
+  ) -> anyhow::Result<Self::Input> {
+    let playground = input.parse::<Playground>()?;
+
+    Ok(Playground {
+      connections: parameters.get_or("connections", PAIRS)?,
+      ..playground
+    })
+  }
+
+  fn part1(playground: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(playground.circuits(playground.connections, TOP).value)
+  }
+
+  fn part2(playground: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(playground.complete_circuit().value)
+  }
+
+  fn diagnostics(playground: &Self::Input) -> anyhow::Result<Diagnostics> {
+    let circuits = playground.circuits(playground.connections, TOP);
+    let complete = playground.complete_circuit();
+
+    Diagnostics::default()
+      .with(
+        "top_circuits",
+        circuits.top.iter().map(Circuit::boxes).collect::<Vec<_>>(),
+      )?
+      .with(
+        "last_pair",
+        [complete.last.lhs.to_string(), complete.last.rhs.to_string()],
+      )
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Playground {
+  boxes: Vec<JunctionBox>,
+  pairs: Vec<JunctionBoxPair>,
+  /// Closest pairs connected before counting circuits
+  connections: usize,
+}
+
+impl Playground {
+  fn complete_circuit(&self) -> CompleteCircuitSolution {
+    let mut sets = UnionFind::new(self.boxes.len());
+    let mut last = Option::<JunctionBoxPair>::None;
+
+    for pair in self.pairs.iter() {
+      if sets.union(pair.indices.0, pair.indices.1) {
+        last = Some(*pair);
+        if sets.components() == 1 {
+          break;
+        }
+      }
+    }
+
+    let Some(last) = last else {
+      return CompleteCircuitSolution::default();
+    };
+
+    let Some(circuit) = self.group(&mut sets).into_iter().next() else {
+      return CompleteCircuitSolution::default();
+    };
+
+    let value = last.lhs.position.x.saturating_mul(last.rhs.position.x);
+
+    CompleteCircuitSolution {
+      circuit,
+      last,
+      value,
+    }
+  }
+
+  fn circuits(&self, pairs: usize, top: usize) -> CircuitSolution {
+    let mut sets = UnionFind::new(self.boxes.len());
+    for pair in self.pairs.iter().take(pairs) {
+      sets.union(pair.indices.0, pair.indices.1);
+    }
+    let mut circuits = self.group(&mut sets);
+
+    circuits.sort_by_key(|circuit| circuit.boxes.len());
+    circuits.reverse();
+
+    let top = circuits.iter().take(top).cloned().collect::<Vec<_>>();
+    let value = top
+      .iter()
+      .map(|circuit| circuit.boxes.len())
+      .fold(1 as Solution, |solution, circuit_len| {
+        solution.saturating_mul(circuit_len)
+      });
+
+    CircuitSolution {
+      circuits,
+      top,
+      value,
+    }
+  }
+
+  fn group(&self, sets: &mut UnionFind) -> Vec<Circuit> {
+    sets
+      .groups()
+      .into_iter()
+      .map(|members| {
+        members
+          .into_iter()
+          .filter_map(|index| self.boxes.get(index).copied())
+          .collect()
+      })
+      .collect()
+  }
+}
+
+impl Display for Playground {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "\nJunction boxes:\n")?;
+    for r#box in self.boxes.iter() {
+      writeln!(f, "{}", r#box)?;
+    }
+    write!(f, "\n\nJunction box pairs:\n\n")?;
+    for pair in self.pairs.iter() {
+      write!(f, "{}\n\n", pair)?;
+    }
+    Ok(())
+  }
+}
+
+impl FromStr for Playground {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let boxes = s
+      .trim()
+      .split('\n')
+      .map(|junction_box| junction_box.parse::<JunctionBox>())
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let mut pairs = boxes
+      .iter()
+      .enumerate()
+      .flat_map(|(index, &r#box)| {
+        boxes
+          .iter()
+          .enumerate()
+          .skip(index.saturating_add(1))
+          .filter(move |(_, &inner)| inner != r#box)
+          .map(move |(inner_index, &inner)| {
+            JunctionBoxPair::new((index, inner_index), r#box, inner)
+          })
+      })
+      .collect::<Vec<_>>();
+    pairs.sort_by_key(|pair| pair.dist);
+
+    Ok(Self {
+      boxes,
+      pairs,
+      connections: PAIRS,
+    })
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+struct CompleteCircuitSolution {
+  circuit: Circuit,
+  last: JunctionBoxPair,
+  value: Solution,
+}
+
+impl Display for CompleteCircuitSolution {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Circuit: {}\n\n", self.circuit)?;
+
+    let last = self.last;
+    write!(f, "Last: {last}\n\n")?;
+
+    let value = self.value;
+    write!(f, "Value: {value}")?;
+
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone)]
+struct CircuitSolution {
+  circuits: Vec<Circuit>,
+  top: Vec<Circuit>,
+  value: Solution,
+}
+
+impl Display for CircuitSolution {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let circuits_display = Circuit::display(self.circuits.iter());
+    write!(f, "Circuits: {}\n\n", circuits_display)?;
+
+    let top_display = Circuit::display(self.top.iter());
+    write!(f, "Top: {}\n\n", top_display)?;
+
+    write!(f, "Value: {}", self.value)?;
+
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Circuit {
+  boxes: HashSet<JunctionBox>,
+}
+
+impl Circuit {
+  /// Boxes of the circuit in a stable order.
+  fn boxes(&self) -> Vec<String> {
+    self
+      .boxes
+      .iter()
+      .map(ToString::to_string)
+      .sorted()
+      .collect()
+  }
+
+  fn display<'a, T: Iterator<Item = &'a Circuit>>(mut circuits: T) -> String {
+    circuits.join("\n")
+  }
+}
+
+impl Display for Circuit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.boxes.iter().join(" <-> "))
+  }
+}
+
+impl FromIterator<JunctionBox> for Circuit {
+  fn from_iter<T: IntoIterator<Item = JunctionBox>>(iter: T) -> Self {
+    let mut boxes = HashSet::new();
+    for r#box in iter.into_iter() {
+      boxes.insert(r#box);
+    }
+    Self { boxes }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct JunctionBoxPair {
+  indices: (usize, usize),
+  lhs: JunctionBox,
+  rhs: JunctionBox,
+  dist: Distance,
+}
+
+impl JunctionBoxPair {
+  fn new(indices: (usize, usize), lhs: JunctionBox, rhs: JunctionBox) -> Self {
+    let dist = lhs.position.euclidean_squared(rhs.position);
+    Self {
+      indices,
+      lhs,
+      rhs,
+      dist,
+    }
+  }
+}
+
+impl PartialEq for JunctionBoxPair {
+  fn eq(&self, other: &Self) -> bool {
+    self.lhs == other.lhs && self.rhs == other.rhs
+  }
+}
+
+impl Eq for JunctionBoxPair {}
+
+impl std::hash::Hash for JunctionBoxPair {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.lhs.hash(state);
+    self.rhs.hash(state);
+  }
+}
+
+impl Display for JunctionBoxPair {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "Lhs: {}\nRhs: {}\nSquared dist: {}",
+      self.lhs, self.rhs, self.dist
+    )
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct JunctionBox {
+  position: Point3,
+}
+
+impl Display for JunctionBox {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}{COORDINATE_SEPARATOR}{}{COORDINATE_SEPARATOR}{}",
+      self.position.x, self.position.y, self.position.z
+    )
+  }
+}
+
+impl FromStr for JunctionBox {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut result = JunctionBox::default();
+
+    let coordinates = s
+      .trim()
+      .split(COORDINATE_SEPARATOR)
+      .map(|coordinate| coordinate.parse::<Coordinate>())
+      .collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(&x) = coordinates.first() {
+      result.position.x = x;
+    }
+    if let Some(&y) = coordinates.get(1) {
+      result.position.y = y;
+    }
+    if let Some(&z) = coordinates.get(2) {
+      result.position.z = z;
+    }
+
+    Ok(result)
+  }
+}
+
+type Solution = usize;
+
+type Distance = usize;
+
+type Coordinate = usize;
+
+const COORDINATE_SEPARATOR: char = ',';
+
+const PAIRS: usize = 1000;
+const TOP: usize = 3;
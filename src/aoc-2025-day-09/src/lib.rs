@@ -0,0 +1,329 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use std::{fmt::Display, str::FromStr};
+
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_geometry::{Point2, Vector2};
+use grind_grid::{Grid, SparseGrid};
+use itertools::Itertools;
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day09>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day09;
+
+impl Solution for Day09 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 9;
+
+  type Input = MovieTheater;
+  type Part1 = Area;
+  type Part2 = Area;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let movie_theater = input.parse::<MovieTheater>()?;
+
+    Ok(movie_theater)
+  }
+
+  fn part1(movie_theater: &Self::Input) -> anyhow::Result<Self::Part1> {
+    Ok(movie_theater.max_rectangle_area())
+  }
+
+  fn part2(movie_theater: &Self::Input) -> anyhow::Result<Self::Part2> {
+    Ok(movie_theater.max_green_rectangle_area())
+  }
+}
+
+#[derive(Debug, Clone)]
+struct MovieTheater {
+  red_tiles: Vec<Point2>,
+  floor: Floor,
+}
+
+impl MovieTheater {
+  fn max_green_rectangle_area(&self) -> Area {
+    self
+      .red_tiles
+      .iter()
+      .enumerate()
+      .flat_map(|(lhs_index, &lhs)| {
+        self
+          .red_tiles
+          .iter()
+          .skip(lhs_index.saturating_add(1))
+          .filter(move |&&rhs| self.floor.covers(lhs, rhs))
+          .map(move |&rhs| Rectangle::new(lhs, rhs).area)
+      })
+      .max_by(Area::total_cmp)
+      .unwrap_or(0 as Area)
+  }
+
+  fn max_rectangle_area(&self) -> Area {
+    let mut rectangles = self
+      .red_tiles
+      .iter()
+      .enumerate()
+      .flat_map(|(lhs_index, &lhs)| {
+        self
+          .red_tiles
+          .iter()
+          .skip(lhs_index.saturating_add(1))
+          .filter(move |&&rhs| lhs != rhs)
+          .map(move |&rhs| Rectangle::new(lhs, rhs))
+      })
+      .collect::<Vec<_>>();
+    rectangles.sort_by(|lhs, rhs| lhs.area.total_cmp(&rhs.area));
+    rectangles.reverse();
+    rectangles
+      .first()
+      .map_or(0 as Area, |rectangle| rectangle.area)
+  }
+}
+
+impl Display for MovieTheater {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      self
+        .red_tiles
+        .iter()
+        .map(|red_tile| format!(
+          "{}{COORDINATE_SEPARATOR}{}",
+          red_tile.x, red_tile.y
+        ))
+        .join(RED_TILE_SEPARATOR_STR)
+    )
+  }
+}
+
+impl FromStr for MovieTheater {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let red_tiles = s
+      .trim()
+      .split(RED_TILE_SEPARATOR_CHAR)
+      .map(parse_red_tile)
+      .collect::<anyhow::Result<Vec<_>>>()?;
+    let floor = Floor::new(&red_tiles)?;
+
+    Ok(Self { red_tiles, floor })
+  }
+}
+
+const RED_TILE_SEPARATOR_CHAR: char = '\n';
+const RED_TILE_SEPARATOR_STR: &str = "\n";
+
+/// Red and green tiles squeezed down to the columns and rows with red tiles
+/// and the gaps between them.
+#[derive(Debug, Clone)]
+struct Floor {
+  xs: Axis,
+  ys: Axis,
+  /// Squeezed tiles outside the loop above and left of each position, so
+  /// one row and column larger than the squeezed floor
+  outside: Grid<usize>,
+}
+
+impl Floor {
+  fn new(red_tiles: &[Point2]) -> anyhow::Result<Self> {
+    let xs = Axis::new(red_tiles.iter().map(|tile| tile.x))?;
+    let ys = Axis::new(red_tiles.iter().map(|tile| tile.y))?;
+
+    let mut tiles = SparseGrid::new();
+    for (&from, &to) in red_tiles.iter().circular_tuple_windows() {
+      let squeeze = |tile: Point2| {
+        Some(Vector2::new(xs.squeeze(tile.x)?, ys.squeeze(tile.y)?))
+      };
+      let (from, to) = squeeze(from).zip(squeeze(to)).ok_or_else(|| {
+        anyhow::anyhow!("Red tiles {from} and {to} are off the floor")
+      })?;
+      if from.x != to.x && from.y != to.y {
+        return Err(anyhow::anyhow!(
+          "Red tiles {from} and {to} aren't in the same row or column"
+        ));
+      }
+      for y in from.y.min(to.y)..=from.y.max(to.y) {
+        for x in from.x.min(to.x)..=from.x.max(to.x) {
+          tiles.insert(Vector2 { x, y }, FloorTile::Loop);
+        }
+      }
+    }
+
+    // NOTE: the ring just past the loop is all outside so the fill starts
+    // in its corner and goes around the loop through negative coordinates
+    let (min, max) = tiles
+      .window()
+      .and_then(|(min, max)| {
+        Some((
+          min.checked_add(Vector2::NORTH_WEST)?,
+          max.checked_add(Vector2::SOUTH_EAST)?,
+        ))
+      })
+      .ok_or_else(|| anyhow::anyhow!("Floor has no red tiles"))?;
+    let mut stack = vec![min];
+    while let Some(position) = stack.pop() {
+      if position.x < min.x
+        || position.y < min.y
+        || position.x > max.x
+        || position.y > max.y
+        || tiles.contains(position)
+      {
+        continue;
+      }
+      tiles.insert(position, FloorTile::Outside);
+      stack.extend(tiles.neighbours4(position));
+    }
+
+    let (width, height) = (xs.len(), ys.len());
+    let mut outside =
+      Grid::new(width.saturating_add(1), height.saturating_add(1), 0usize)?;
+    for y in 0..height {
+      for x in 0..width {
+        let tile = isize::try_from(x)
+          .ok()
+          .zip(isize::try_from(y).ok())
+          .and_then(|(x, y)| tiles.get(Vector2 { x, y }));
+        let before =
+          |x, y| outside.get(Point2 { x, y }).copied().unwrap_or_default();
+        let (next_x, next_y) = (x.saturating_add(1), y.saturating_add(1));
+        let count = before(next_x, y)
+          .saturating_add(before(x, next_y))
+          .saturating_sub(before(x, y))
+          .saturating_add(usize::from(tile == Some(&FloorTile::Outside)));
+        if let Some(cell) = outside.get_mut(Point2::new(next_x, next_y)) {
+          *cell = count;
+        }
+      }
+    }
+
+    Ok(Self { xs, ys, outside })
+  }
+
+  /// Whether every tile of the rectangle between two red tiles is red or
+  /// green.
+  fn covers(&self, lhs: Point2, rhs: Point2) -> bool {
+    let squeeze = |tile: Point2| {
+      Some(Point2 {
+        x: usize::try_from(self.xs.squeeze(tile.x)?).ok()?,
+        y: usize::try_from(self.ys.squeeze(tile.y)?).ok()?,
+      })
+    };
+    let Some((lhs, rhs)) = squeeze(lhs).zip(squeeze(rhs)) else {
+      return false;
+    };
+    let (min_x, max_x) = (lhs.x.min(rhs.x), lhs.x.max(rhs.x).saturating_add(1));
+    let (min_y, max_y) = (lhs.y.min(rhs.y), lhs.y.max(rhs.y).saturating_add(1));
+
+    let before = |x, y| self.outside.get(Point2 { x, y }).copied();
+    let outside = before(max_x, max_y)
+      .zip(before(min_x, min_y))
+      .and_then(|(lhs, rhs)| lhs.checked_add(rhs))
+      .zip(before(min_x, max_y).zip(before(max_x, min_y)))
+      .and_then(|(sum, (lhs, rhs))| sum.checked_sub(lhs)?.checked_sub(rhs));
+    outside == Some(0)
+  }
+}
+
+/// Red tile coordinates along one axis, each squeezed next to the one before
+/// or a gap further when there are tiles in between.
+#[derive(Debug, Clone)]
+struct Axis {
+  coordinates: Vec<Coordinate>,
+  squeezed: Vec<isize>,
+}
+
+impl Axis {
+  fn new(
+    coordinates: impl Iterator<Item = Coordinate>,
+  ) -> anyhow::Result<Self> {
+    let coordinates = coordinates.sorted().dedup().collect::<Vec<_>>();
+    let squeezed = coordinates
+      .iter()
+      .scan(None, |previous: &mut Option<(Coordinate, isize)>, &next| {
+        let squeezed = match *previous {
+          Some((coordinate, squeezed)) if next.abs_diff(coordinate) > 1 => {
+            squeezed.checked_add(2)
+          }
+          Some((_, squeezed)) => squeezed.checked_add(1),
+          None => Some(0),
+        };
+        *previous = squeezed.map(|squeezed| (next, squeezed));
+        Some(squeezed)
+      })
+      .collect::<Option<Vec<_>>>()
+      .ok_or_else(|| anyhow::anyhow!("Floor is too wide to squeeze"))?;
+
+    Ok(Self {
+      coordinates,
+      squeezed,
+    })
+  }
+
+  fn squeeze(&self, coordinate: Coordinate) -> Option<isize> {
+    let index = self.coordinates.binary_search(&coordinate).ok()?;
+    self.squeezed.get(index).copied()
+  }
+
+  /// Squeezed coordinates including the gaps.
+  fn len(&self) -> usize {
+    self
+      .squeezed
+      .last()
+      .and_then(|&last| usize::try_from(last).ok())
+      .map_or(0, |last| last.saturating_add(1))
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloorTile {
+  Loop,
+  Outside,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rectangle {
+  #[allow(dead_code, reason = "useful for debug")]
+  lhs: Point2,
+  #[allow(dead_code, reason = "useful for debug")]
+  rhs: Point2,
+  area: Area,
+}
+
+impl Rectangle {
+  fn new(lhs: Point2, rhs: Point2) -> Self {
+    Self {
+      lhs,
+      rhs,
+      area: (((lhs.x as Area) - (rhs.x as Area)).abs() + 1.0)
+        * (((lhs.y as Area) - rhs.y as Area).abs() + 1.0),
+    }
+  }
+}
+
+type Area = f64;
+
+fn parse_red_tile(s: &str) -> anyhow::Result<Point2> {
+  let Some((x, y)) = s.trim().split(COORDINATE_SEPARATOR).collect_tuple()
+  else {
+    return Err(anyhow::anyhow!("invalid red tile"));
+  };
+
+  Ok(Point2 {
+    x: x.parse::<Coordinate>()?,
+    y: y.parse::<Coordinate>()?,
+  })
+}
+
+type Coordinate = usize;
+
+const COORDINATE_SEPARATOR: char = ',';
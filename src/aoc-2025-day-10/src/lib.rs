@@ -0,0 +1,316 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use anyhow::Context;
+use grind_core::{Day, Puzzle, Solution, Year};
+use grind_grid::Tile;
+use grind_math::{solve, solve_gf2, Rational};
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+pub const PUZZLE: Puzzle =
+  Puzzle::new::<Day10>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+grind_core::examples!(PUZZLE);
+
+struct Day10;
+
+impl Solution for Day10 {
+  const YEAR: Year = 2025;
+  const DAY: Day = 10;
+
+  type Input = Factory;
+  type Part1 = usize;
+  type Part2 = usize;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input> {
+    let factory = input.parse::<Factory>()?;
+
+    Ok(factory)
+  }
+
+  fn part1(factory: &Self::Input) -> anyhow::Result<Self::Part1> {
+    factory.configure_presses()
+  }
+
+  fn part2(factory: &Self::Input) -> anyhow::Result<Self::Part2> {
+    factory.configure_presses_with_joltage()
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Factory(Vec<Machine>);
+
+impl Factory {
+  fn machines(&self) -> &Vec<Machine> {
+    &self.0
+  }
+
+  fn configure_presses(&self) -> anyhow::Result<usize> {
+    self.machines().iter().try_fold(0_usize, |sum, machine| {
+      Ok(sum.saturating_add(machine.configure_presses()?))
+    })
+  }
+
+  fn configure_presses_with_joltage(&self) -> anyhow::Result<usize> {
+    self.machines().iter().try_fold(0_usize, |sum, machine| {
+      Ok(sum.saturating_add(machine.configure_presses_with_joltage()?))
+    })
+  }
+}
+
+impl Display for Factory {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.machines().iter().join("\n"))
+  }
+}
+
+impl FromStr for Factory {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let machines = s
+      .trim()
+      .split('\n')
+      .enumerate()
+      .map(|(index, line)| {
+        line.trim().parse::<Machine>().with_context(|| {
+          format!("invalid machine on line {}", index.saturating_add(1))
+        })
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Self(machines))
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Machine {
+  indicator_lights: Vec<IndicatorLight>,
+  buttons: Vec<Button>,
+  joltage_requirements: Vec<JoltageRequirement>,
+}
+
+impl Machine {
+  /// Fewest presses switching the lights into place, pressing a button twice
+  /// being the same as not pressing it.
+  fn configure_presses(&self) -> anyhow::Result<usize> {
+    let equations = self
+      .indicator_lights
+      .iter()
+      .enumerate()
+      .map(|(light, indicator_light)| {
+        Ok((
+          self.buttons_for(light)?,
+          *indicator_light == IndicatorLight::On,
+        ))
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    solve_gf2(equations, u32::try_from(self.buttons.len())?)
+      .and_then(|space| {
+        space
+          .solutions()
+          .map(|presses| presses.count_ones() as usize)
+          .min()
+      })
+      .ok_or_else(|| anyhow::anyhow!("No presses configure machine {self}"))
+  }
+
+  /// Fewest presses raising every counter to its joltage requirement.
+  fn configure_presses_with_joltage(&self) -> anyhow::Result<usize> {
+    let coefficients = (0..self.joltage_requirements.len())
+      .map(|counter| {
+        self
+          .buttons
+          .iter()
+          .map(|button| {
+            Rational::from(u8::from(button.indices().contains(&counter)))
+          })
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    let constants = self
+      .joltage_requirements
+      .iter()
+      .map(|&requirement| Rational::from(requirement))
+      .collect::<Vec<_>>();
+
+    // NOTE: no button can be pressed more often than the lowest requirement
+    // of the counters it raises, and one raising none is never worth pressing
+    let bounds = self
+      .buttons
+      .iter()
+      .map(|button| {
+        let most = button
+          .indices()
+          .filter_map(|counter| self.joltage_requirements.get(counter))
+          .min()
+          .copied()
+          .unwrap_or(0);
+        0..=i128::from(most)
+      })
+      .collect::<Vec<_>>();
+
+    let space = solve(&coefficients, &constants).ok_or_else(|| {
+      anyhow::anyhow!("No presses reach the joltage of machine {self}")
+    })?;
+    if space.has_integer_solution() == Some(false) {
+      return Err(anyhow::anyhow!(
+        "No whole presses reach the joltage of machine {self}"
+      ));
+    }
+    if space
+      .candidates(&bounds)
+      .is_none_or(|candidates| candidates > MAX_CANDIDATES)
+    {
+      return Err(anyhow::anyhow!(
+        "Machine {self} has more than {MAX_CANDIDATES} press candidates"
+      ));
+    }
+
+    let presses = space
+      .integer_solutions(&bounds)
+      .map(|presses| presses.iter().sum::<i128>())
+      .min()
+      .ok_or_else(|| {
+        anyhow::anyhow!("No whole presses reach the joltage of machine {self}")
+      })?;
+    usize::try_from(presses).map_err(|_| {
+      anyhow::anyhow!("Presses {presses} of machine {self} don't fit a usize")
+    })
+  }
+
+  /// Mask of buttons wired to the light or counter at `index`.
+  fn buttons_for(&self, index: IndicatorLightIndex) -> anyhow::Result<u128> {
+    self
+      .buttons
+      .iter()
+      .enumerate()
+      .filter(|(_, button)| button.indices().contains(&index))
+      .try_fold(0, |mask, (button, _)| {
+        let bit = u32::try_from(button)
+          .ok()
+          .and_then(|button| 1u128.checked_shl(button))
+          .ok_or_else(|| {
+            anyhow::anyhow!(
+              "Machine {self} has more than {} buttons",
+              u128::BITS
+            )
+          })?;
+        Ok(mask | bit)
+      })
+  }
+}
+
+impl Display for Machine {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "[{}] {} {{{}}}",
+      self.indicator_lights.iter().join(""),
+      self.buttons.iter().join(" "),
+      self.joltage_requirements.iter().join(",")
+    )
+  }
+}
+
+impl FromStr for Machine {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut sections = s.split_whitespace();
+    let indicator_lights = sections
+      .next()
+      .ok_or_else(|| anyhow::anyhow!("empty machine {s}"))?;
+    let indicator_lights = enclosed(indicator_lights, '[', ']')?
+      .chars()
+      .map(IndicatorLight::try_from)
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let mut sections = sections.collect::<Vec<_>>();
+    let joltage_requirements = sections
+      .pop()
+      .ok_or_else(|| anyhow::anyhow!("missing joltage requirements in {s}"))?;
+    let joltage_requirements = enclosed(joltage_requirements, '{', '}')?
+      .split(',')
+      .map(|joltage_requirement| {
+        joltage_requirement.parse::<JoltageRequirement>()
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let buttons = sections
+      .iter()
+      .map(|section| section.parse::<Button>())
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Self {
+      indicator_lights,
+      buttons,
+      joltage_requirements,
+    })
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Button(u64);
+
+impl Button {
+  fn indices(&self) -> impl Iterator<Item = IndicatorLightIndex> {
+    let val = self.0;
+    (0..64)
+      .filter(move |i| val & (1 << i) != 0)
+      .map(|i| i as IndicatorLightIndex)
+  }
+}
+
+impl Display for Button {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "({})", self.indices().join(","))
+  }
+}
+
+impl FromStr for Button {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let wiring =
+      enclosed(s, '(', ')')?
+        .split(',')
+        .try_fold(0_u64, |wiring, index| {
+          let bit =
+            1u64.checked_shl(index.parse::<u32>()?).ok_or_else(|| {
+              anyhow::anyhow!("button index {index} is past {}", u64::BITS)
+            })?;
+          Ok::<_, anyhow::Error>(wiring | bit)
+        })?;
+
+    Ok(Button(wiring))
+  }
+}
+
+/// `s` without the `open` and `close` characters around it.
+fn enclosed(s: &str, open: char, close: char) -> anyhow::Result<&str> {
+  s.strip_prefix(open)
+    .and_then(|s| s.strip_suffix(close))
+    .ok_or_else(|| anyhow::anyhow!("expected {s} to be in {open}{close}"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+enum IndicatorLight {
+  #[tile('#')]
+  On,
+  #[tile('.')]
+  Off,
+}
+
+type IndicatorLightIndex = usize;
+
+type JoltageRequirement = u64;
+
+/// Most combinations of free button presses tried for a single machine.
+const MAX_CANDIDATES: u128 = 1 << 24;
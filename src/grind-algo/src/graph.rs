@@ -0,0 +1,32 @@
+use std::hash::Hash;
+
+/// Graph known only through the successors of each state.
+pub trait Graph {
+  type State: Clone + Eq + Hash;
+  type Cost: Cost;
+
+  /// States one edge away from `state` with the cost of that edge.
+  fn successors(
+    &self,
+    state: &Self::State,
+  ) -> impl Iterator<Item = (Self::State, Self::Cost)>;
+}
+
+/// Edge and path cost that adds up without overflowing.
+pub trait Cost: Copy + Ord + Default {
+  fn saturating_add(self, other: Self) -> Self;
+}
+
+macro_rules! cost {
+  ($($type:ty),+) => {
+    $(
+      impl Cost for $type {
+        fn saturating_add(self, other: Self) -> Self {
+          <$type>::saturating_add(self, other)
+        }
+      }
+    )+
+  };
+}
+
+cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
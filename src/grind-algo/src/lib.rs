@@ -0,0 +1,20 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+pub mod graph;
+pub mod memo;
+pub mod search;
+pub mod stepper;
+pub mod topology;
+pub mod union_find;
+
+pub use graph::{Cost, Graph};
+pub use memo::Memo;
+pub use search::{astar, bfs, dijkstra, Search};
+pub use stepper::{History, Stepper};
+pub use topology::{strongly_connected, toposort};
+pub use union_find::UnionFind;
@@ -0,0 +1,130 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Results of a function remembered by its arguments.
+///
+/// Recursive functions take the memo along and go through
+/// [`Memo::get_or_insert_with`] so every call with the same arguments after the
+/// first is a lookup.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+  cache: HashMap<K, V>,
+  limit: Option<usize>,
+  hits: usize,
+  misses: usize,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+  pub fn new() -> Self {
+    Self {
+      cache: HashMap::new(),
+      limit: None,
+      hits: 0,
+      misses: 0,
+    }
+  }
+
+  /// Remembers at most `limit` results, computing the rest every time.
+  pub fn with_limit(limit: usize) -> Self {
+    Self {
+      limit: Some(limit),
+      ..Self::new()
+    }
+  }
+
+  /// Remembered result for `key` or the one `compute` gives with this memo
+  /// for its own recursive calls.
+  pub fn get_or_insert_with(
+    &mut self,
+    key: K,
+    compute: impl FnOnce(&mut Self) -> V,
+  ) -> V {
+    let result = self.get_or_try_insert_with(key, |memo| {
+      Ok::<_, std::convert::Infallible>(compute(memo))
+    });
+    match result {
+      Ok(value) => value,
+      Err(infallible) => match infallible {},
+    }
+  }
+
+  /// Like [`Memo::get_or_insert_with`] but errors are passed on without
+  /// being remembered.
+  pub fn get_or_try_insert_with<E>(
+    &mut self,
+    key: K,
+    compute: impl FnOnce(&mut Self) -> Result<V, E>,
+  ) -> Result<V, E> {
+    if let Some(value) = self.cache.get(&key) {
+      self.hits = self.hits.saturating_add(1);
+      return Ok(value.clone());
+    }
+
+    self.misses = self.misses.saturating_add(1);
+    let value = compute(self)?;
+    if self.limit.is_none_or(|limit| self.cache.len() < limit) {
+      self.cache.insert(key, value.clone());
+    }
+    Ok(value)
+  }
+
+  pub fn get(&self, key: &K) -> Option<&V> {
+    self.cache.get(key)
+  }
+
+  /// Number of remembered results.
+  pub fn len(&self) -> usize {
+    self.cache.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.cache.is_empty()
+  }
+
+  /// Calls answered from remembered results.
+  pub fn hits(&self) -> usize {
+    self.hits
+  }
+
+  /// Calls that had to compute their result.
+  pub fn misses(&self) -> usize {
+    self.misses
+  }
+
+  /// Forgets every result and resets the statistics.
+  pub fn clear(&mut self) {
+    self.cache.clear();
+    self.hits = 0;
+    self.misses = 0;
+  }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+    memo.get_or_insert_with(n, |memo| match n.checked_sub(2) {
+      Some(before) => fibonacci(before, memo)
+        .saturating_add(fibonacci(before.saturating_add(1), memo)),
+      None => n,
+    })
+  }
+
+  #[test]
+  fn remembers_results() {
+    let mut memo = Memo::new();
+    assert_eq!(fibonacci(90, &mut memo), 2880067194370816120);
+    assert_eq!((memo.len(), memo.misses(), memo.hits()), (91, 91, 88));
+
+    let mut limited = Memo::with_limit(10);
+    assert_eq!(fibonacci(20, &mut limited), 6765);
+    assert_eq!(limited.len(), 10);
+    assert_eq!(limited.get(&20), None);
+  }
+}
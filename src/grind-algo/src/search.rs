@@ -0,0 +1,347 @@
+use std::{
+  cmp::Reverse,
+  collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+  hash::Hash,
+};
+
+use crate::{Cost, Graph};
+
+/// Settled states of a search with their cost and every predecessor on an
+/// optimal path to them.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+  costs: HashMap<S, C>,
+  predecessors: HashMap<S, Vec<S>>,
+  settled: Vec<S>,
+  goals: Vec<S>,
+}
+
+/// Counts edges instead of adding up their costs.
+pub fn bfs<G: Graph>(
+  graph: &G,
+  starts: impl IntoIterator<Item = G::State>,
+  is_goal: impl Fn(&G::State) -> bool,
+) -> Search<G::State, usize> {
+  search(graph, starts, is_goal, |_| 1, |_| 0, Fifo(VecDeque::new()))
+}
+
+pub fn dijkstra<G: Graph>(
+  graph: &G,
+  starts: impl IntoIterator<Item = G::State>,
+  is_goal: impl Fn(&G::State) -> bool,
+) -> Search<G::State, G::Cost> {
+  search(
+    graph,
+    starts,
+    is_goal,
+    |cost| cost,
+    |_| G::Cost::default(),
+    Heap(BinaryHeap::new()),
+  )
+}
+
+/// Paths stay optimal as long as `heuristic` never overestimates and never
+/// drops by more than an edge costs.
+pub fn astar<G: Graph>(
+  graph: &G,
+  starts: impl IntoIterator<Item = G::State>,
+  is_goal: impl Fn(&G::State) -> bool,
+  heuristic: impl Fn(&G::State) -> G::Cost,
+) -> Search<G::State, G::Cost> {
+  search(
+    graph,
+    starts,
+    is_goal,
+    |cost| cost,
+    heuristic,
+    Heap(BinaryHeap::new()),
+  )
+}
+
+// NOTE: the search goes on until the frontier runs past the cheapest goal so
+// that every goal and predecessor tied for optimal gets recorded
+fn search<G: Graph, C: Cost>(
+  graph: &G,
+  starts: impl IntoIterator<Item = G::State>,
+  is_goal: impl Fn(&G::State) -> bool,
+  weigh: impl Fn(G::Cost) -> C,
+  heuristic: impl Fn(&G::State) -> C,
+  mut frontier: impl Frontier<C>,
+) -> Search<G::State, C> {
+  let mut states = Vec::new();
+  let mut costs = HashMap::new();
+  let mut predecessors: HashMap<G::State, Vec<G::State>> = HashMap::new();
+  let mut settled = Vec::new();
+  let mut settled_set = HashSet::new();
+  let mut goals = Vec::new();
+  let mut goal_cost = None;
+
+  for start in starts {
+    if costs.insert(start.clone(), C::default()).is_none() {
+      frontier.push(heuristic(&start), C::default(), states.len());
+      states.push(start);
+    }
+  }
+
+  while let Some((priority, cost, index)) = frontier.pop() {
+    if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+      break;
+    }
+    let Some(state) = states.get(index).cloned() else {
+      continue;
+    };
+    if costs.get(&state).is_some_and(|&best| cost > best)
+      || !settled_set.insert(state.clone())
+    {
+      continue;
+    }
+    settled.push(state.clone());
+
+    if is_goal(&state) {
+      goal_cost = Some(cost);
+      goals.push(state);
+      continue;
+    }
+
+    for (next, step) in graph.successors(&state) {
+      let next_cost = cost.saturating_add(weigh(step));
+      match costs.get(&next) {
+        Some(&best) if next_cost > best => {}
+        Some(&best) if next_cost == best => {
+          predecessors.entry(next).or_default().push(state.clone());
+        }
+        _ => {
+          costs.insert(next.clone(), next_cost);
+          predecessors.insert(next.clone(), vec![state.clone()]);
+          frontier.push(
+            next_cost.saturating_add(heuristic(&next)),
+            next_cost,
+            states.len(),
+          );
+          states.push(next);
+        }
+      }
+    }
+  }
+
+  costs.retain(|state, _| settled_set.contains(state));
+  predecessors.retain(|state, _| settled_set.contains(state));
+
+  Search {
+    costs,
+    predecessors,
+    settled,
+    goals,
+  }
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Search<S, C> {
+  /// Cost of the cheapest path to `state` if the search got to it.
+  pub fn cost(&self, state: &S) -> Option<C> {
+    self.costs.get(state).copied()
+  }
+
+  /// Goals reached at the cheapest cost, in the order they were reached.
+  pub fn goals(&self) -> &[S] {
+    &self.goals
+  }
+
+  pub fn goal_cost(&self) -> Option<C> {
+    self.goals.first().and_then(|goal| self.cost(goal))
+  }
+
+  /// Settled states cheapest first.
+  pub fn reached(&self) -> impl Iterator<Item = (&S, C)> {
+    self
+      .settled
+      .iter()
+      .filter_map(|state| Some((state, self.cost(state)?)))
+  }
+
+  /// One cheapest path from a start to `to`, both included.
+  pub fn path(&self, to: &S) -> Option<Vec<S>> {
+    self.cost(to)?;
+
+    let mut path = vec![to.clone()];
+    let mut current = to;
+    while let Some(previous) = self
+      .predecessors
+      .get(current)
+      .and_then(|predecessors| predecessors.first())
+    {
+      path.push(previous.clone());
+      current = previous;
+    }
+    path.reverse();
+
+    Some(path)
+  }
+
+  pub fn path_to_goal(&self) -> Option<Vec<S>> {
+    self.path(self.goals.first()?)
+  }
+
+  /// Every state on any cheapest path ending in one of `ends`.
+  pub fn on_paths_to(&self, ends: impl IntoIterator<Item = S>) -> HashSet<S> {
+    let mut on_paths = HashSet::new();
+    let mut stack = ends
+      .into_iter()
+      .filter(|end| self.costs.contains_key(end))
+      .collect::<Vec<_>>();
+
+    while let Some(state) = stack.pop() {
+      if let Some(predecessors) = self.predecessors.get(&state) {
+        stack.extend(
+          predecessors
+            .iter()
+            .filter(|predecessor| !on_paths.contains(*predecessor))
+            .cloned(),
+        );
+      }
+      on_paths.insert(state);
+    }
+
+    on_paths
+  }
+
+  pub fn on_paths_to_goals(&self) -> HashSet<S> {
+    self.on_paths_to(self.goals.iter().cloned())
+  }
+
+  /// Number of distinct cheapest paths from any start to `to`.
+  pub fn path_count(&self, to: &S) -> usize {
+    let mut counts: HashMap<&S, usize> = HashMap::new();
+    for state in self.settled.iter() {
+      let count = match self.predecessors.get(state) {
+        Some(predecessors) => predecessors
+          .iter()
+          .filter_map(|predecessor| counts.get(predecessor))
+          .fold(0usize, |sum, count| sum.saturating_add(*count)),
+        None => 1,
+      };
+      if state == to {
+        return count;
+      }
+      counts.insert(state, count);
+    }
+
+    0
+  }
+}
+
+/// Order states get expanded in, as priority, cost and index of the state.
+trait Frontier<C> {
+  fn push(&mut self, priority: C, cost: C, index: usize);
+  fn pop(&mut self) -> Option<(C, C, usize)>;
+}
+
+struct Fifo<C>(VecDeque<(C, C, usize)>);
+
+impl<C> Frontier<C> for Fifo<C> {
+  fn push(&mut self, priority: C, cost: C, index: usize) {
+    self.0.push_back((priority, cost, index));
+  }
+
+  fn pop(&mut self) -> Option<(C, C, usize)> {
+    self.0.pop_front()
+  }
+}
+
+struct Heap<C>(BinaryHeap<Reverse<(C, C, usize)>>);
+
+impl<C: Ord> Frontier<C> for Heap<C> {
+  fn push(&mut self, priority: C, cost: C, index: usize) {
+    self.0.push(Reverse((priority, cost, index)));
+  }
+
+  fn pop(&mut self) -> Option<(C, C, usize)> {
+    self.0.pop().map(|Reverse(entry)| entry)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Two routes of cost 4 and one of cost 5 from 0 to 3.
+  struct Diamond;
+
+  impl Graph for Diamond {
+    type State = u8;
+    type Cost = u32;
+
+    fn successors(&self, state: &u8) -> impl Iterator<Item = (u8, u32)> {
+      let edges: &[(u8, u32)] = match state {
+        0 => &[(1, 1), (2, 3), (4, 1)],
+        1 => &[(3, 3)],
+        2 => &[(3, 1)],
+        4 => &[(3, 4)],
+        _ => &[],
+      };
+      edges.iter().copied()
+    }
+  }
+
+  #[test]
+  fn finds_every_cheapest_path() {
+    let search = dijkstra(&Diamond, [0], |&state| state == 3);
+
+    assert_eq!(search.goal_cost(), Some(4));
+    assert_eq!(search.path_count(&3), 2);
+    assert_eq!(search.path_to_goal().map(|path| path.len()), Some(3));
+    assert_eq!(search.on_paths_to_goals(), HashSet::from([0, 1, 2, 3]));
+  }
+
+  #[test]
+  fn agrees_across_searches() {
+    let astar = astar(
+      &Diamond,
+      [0],
+      |&state| state == 3,
+      |&state| u32::from(state == 1),
+    );
+    let bfs = bfs(&Diamond, [0], |&state| state == 3);
+
+    assert_eq!(astar.goal_cost(), Some(4));
+    assert_eq!(astar.on_paths_to_goals().len(), 4);
+    assert_eq!(bfs.goal_cost(), Some(2));
+    assert_eq!(bfs.path_count(&3), 3);
+  }
+
+  #[test]
+  fn gives_up_on_unreachable_goals() {
+    let search = dijkstra(&Diamond, [0], |&state| state == 5);
+
+    assert_eq!(search.goals(), &[]);
+    assert_eq!(search.goal_cost(), None);
+    assert_eq!(search.path_to_goal(), None);
+    assert!(search.on_paths_to_goals().is_empty());
+    assert_eq!(search.path_count(&5), 0);
+    assert_eq!(search.reached().count(), 5);
+  }
+
+  #[test]
+  fn keeps_every_goal_tied_for_cheapest() {
+    let tied = dijkstra(&Diamond, [0], |&state| state == 1 || state == 4);
+    assert_eq!(tied.goals(), &[1, 4]);
+    assert_eq!(tied.goal_cost(), Some(1));
+    assert_eq!(tied.on_paths_to_goals(), HashSet::from([0, 1, 4]));
+
+    let untied = dijkstra(&Diamond, [0], |&state| state == 2 || state == 3);
+    assert_eq!(untied.goals(), &[2]);
+    assert_eq!(untied.cost(&3), None);
+  }
+
+  #[test]
+  fn counts_paths_to_any_state() {
+    let search = dijkstra(&Diamond, [0], |_| false);
+
+    assert_eq!(search.path_count(&0), 1);
+    assert_eq!(search.path_count(&2), 1);
+    assert_eq!(search.path_count(&3), 2);
+
+    let starts = dijkstra(&Diamond, [1, 4], |_| false);
+    assert_eq!(starts.path_count(&3), 1);
+    assert_eq!(starts.cost(&3), Some(3));
+  }
+}
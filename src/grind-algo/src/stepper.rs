@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+/// Simulation that moves its state along one step at a time.
+pub trait Stepper {
+  type Error;
+
+  /// Moves on to the next state, false once the state stopped changing.
+  fn step(&mut self) -> Result<bool, Self::Error>;
+
+  /// Steps at most `steps` times and returns how many of them changed
+  /// anything.
+  fn run(&mut self, steps: usize) -> Result<usize, Self::Error> {
+    for taken in 0..steps {
+      if !self.step()? {
+        return Ok(taken);
+      }
+    }
+    Ok(steps)
+  }
+
+  /// Steps until the state stops changing and returns how many steps that
+  /// took.
+  fn run_to_fixpoint(&mut self) -> Result<usize, Self::Error> {
+    let mut taken = 0usize;
+    while self.step()? {
+      taken = taken.saturating_add(1);
+    }
+    Ok(taken)
+  }
+
+  /// Steps before the states start repeating and how many states repeat,
+  /// nothing when the simulation reaches a fixpoint instead.
+  ///
+  /// Uses Brent's algorithm so only two states are around at any time.
+  fn cycle(&self) -> Result<Option<(usize, usize)>, Self::Error>
+  where
+    Self: Clone + PartialEq,
+  {
+    let mut power = 1usize;
+    let mut length = 1usize;
+    let mut tortoise = self.clone();
+    let mut hare = self.clone();
+    if !hare.step()? {
+      return Ok(None);
+    }
+    while tortoise != hare {
+      if power == length {
+        tortoise = hare.clone();
+        power = power.saturating_mul(2);
+        length = 0;
+      }
+      if !hare.step()? {
+        return Ok(None);
+      }
+      length = length.saturating_add(1);
+    }
+
+    let mut start = 0usize;
+    let mut tortoise = self.clone();
+    let mut hare = self.clone();
+    hare.run(length)?;
+    while tortoise != hare {
+      tortoise.step()?;
+      hare.step()?;
+      start = start.saturating_add(1);
+    }
+
+    Ok(Some((start, length)))
+  }
+}
+
+/// Stepper that remembers the states it went through before the current
+/// one.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+  current: S,
+  past: VecDeque<S>,
+  limit: Option<usize>,
+}
+
+impl<S> History<S> {
+  pub fn new(state: S) -> Self {
+    Self {
+      current: state,
+      past: VecDeque::new(),
+      limit: None,
+    }
+  }
+
+  /// Remembers at most `limit` past states, forgetting the oldest first.
+  pub fn with_limit(state: S, limit: usize) -> Self {
+    Self {
+      limit: Some(limit),
+      ..Self::new(state)
+    }
+  }
+
+  pub fn current(&self) -> &S {
+    &self.current
+  }
+
+  pub fn into_current(self) -> S {
+    self.current
+  }
+
+  /// Past states from the oldest still remembered.
+  pub fn past(&self) -> impl DoubleEndedIterator<Item = &S> + '_ {
+    self.past.iter()
+  }
+
+  /// Past states from the oldest followed by the current one.
+  pub fn states(&self) -> impl DoubleEndedIterator<Item = &S> + '_ {
+    self.past.iter().chain([&self.current])
+  }
+}
+
+impl<S: Stepper + Clone> Stepper for History<S> {
+  type Error = S::Error;
+
+  fn step(&mut self) -> Result<bool, Self::Error> {
+    let previous = self.current.clone();
+    if !self.current.step()? {
+      return Ok(false);
+    }
+
+    if self.limit != Some(0) {
+      self.past.push_back(previous);
+    }
+    if self.limit.is_some_and(|limit| self.past.len() > limit) {
+      self.past.pop_front();
+    }
+    Ok(true)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Squares plus one modulo a number, stopping at zero.
+  #[derive(Debug, Clone, PartialEq)]
+  struct Squares {
+    value: u64,
+    modulus: u64,
+  }
+
+  impl Stepper for Squares {
+    type Error = ();
+
+    fn step(&mut self) -> Result<bool, Self::Error> {
+      if self.value == 0 {
+        return Ok(false);
+      }
+      self.value = self
+        .value
+        .checked_mul(self.value)
+        .and_then(|square| square.checked_add(1))
+        .and_then(|next| next.checked_rem(self.modulus))
+        .ok_or(())?;
+      Ok(true)
+    }
+  }
+
+  #[test]
+  fn finds_cycles_and_fixpoints() {
+    // NOTE: 2, 5, 26, 10, 14, 23, 8 and then 7, 21 over and over modulo 29
+    let squares = Squares {
+      value: 2,
+      modulus: 29,
+    };
+    assert_eq!(squares.cycle(), Ok(Some((7, 2))));
+
+    let mut history = History::with_limit(squares.clone(), 3);
+    assert_eq!(history.run(5), Ok(5));
+    assert_eq!(
+      history
+        .states()
+        .map(|state| state.value)
+        .collect::<Vec<_>>(),
+      [26, 10, 14, 23]
+    );
+
+    // NOTE: 2, 5, 6, 7, 0 modulo 10
+    let mut ending = Squares {
+      value: 2,
+      modulus: 10,
+    };
+    assert_eq!(ending.cycle(), Ok(None));
+    assert_eq!(ending.run_to_fixpoint(), Ok(4));
+    assert_eq!(ending.value, 0);
+  }
+}
@@ -0,0 +1,263 @@
+use std::{
+  collections::{HashMap, VecDeque},
+  hash::Hash,
+};
+
+/// Orders nodes so that every edge points forward with Kahn's algorithm,
+/// ties keep the order nodes first show up in.
+///
+/// Nodes only named by `edges` get ordered too. When there is no such order
+/// the error holds the nodes of a cycle in the order its edges go.
+pub fn toposort<N: Clone + Eq + Hash>(
+  nodes: impl IntoIterator<Item = N>,
+  edges: impl IntoIterator<Item = (N, N)>,
+) -> Result<Vec<N>, Vec<N>> {
+  let graph = Indexed::new(nodes, edges);
+
+  let mut in_degrees = vec![0usize; graph.len()];
+  for &successor in graph.successors.iter().flatten() {
+    if let Some(in_degree) = in_degrees.get_mut(successor) {
+      *in_degree = in_degree.saturating_add(1);
+    }
+  }
+
+  let mut queue = in_degrees
+    .iter()
+    .enumerate()
+    .filter(|(_, &in_degree)| in_degree == 0)
+    .map(|(node, _)| node)
+    .collect::<VecDeque<_>>();
+  let mut order = Vec::with_capacity(graph.len());
+  while let Some(node) = queue.pop_front() {
+    order.push(node);
+    for &successor in graph.successors(node) {
+      if let Some(in_degree) = in_degrees.get_mut(successor) {
+        *in_degree = in_degree.saturating_sub(1);
+        if *in_degree == 0 {
+          queue.push_back(successor);
+        }
+      }
+    }
+  }
+
+  if order.len() == graph.len() {
+    return Ok(graph.resolve(order));
+  }
+
+  // NOTE: every node left over still has a predecessor left over so walking
+  // back through those has to run into a cycle
+  let left_over =
+    |node: usize| in_degrees.get(node).is_some_and(|&in_degree| in_degree > 0);
+  let mut predecessors = vec![None; graph.len()];
+  for node in (0..graph.len()).filter(|&node| left_over(node)) {
+    for &successor in graph.successors(node) {
+      if let Some(predecessor) = predecessors.get_mut(successor) {
+        *predecessor = Some(node);
+      }
+    }
+  }
+
+  let mut walked = Vec::new();
+  let mut seen_at = vec![None; graph.len()];
+  let mut current = (0..graph.len()).find(|&node| left_over(node));
+  while let Some(node) = current {
+    let Some(seen) = seen_at.get_mut(node) else {
+      break;
+    };
+    if let Some(start) = *seen {
+      let mut cycle = walked.split_off(start);
+      cycle.reverse();
+      return Err(graph.resolve(cycle));
+    }
+    *seen = Some(walked.len());
+    walked.push(node);
+    current = predecessors.get(node).copied().flatten();
+  }
+
+  Err(Vec::new())
+}
+
+/// Strongly connected components with Tarjan's algorithm, each component
+/// coming before every component with edges into it.
+pub fn strongly_connected<N: Clone + Eq + Hash>(
+  nodes: impl IntoIterator<Item = N>,
+  edges: impl IntoIterator<Item = (N, N)>,
+) -> Vec<Vec<N>> {
+  let graph = Indexed::new(nodes, edges);
+  let mut visits = vec![Visit::default(); graph.len()];
+  let mut next_index = 0usize;
+  let mut stack = Vec::new();
+  let mut components = Vec::new();
+
+  for root in 0..graph.len() {
+    if visits.get(root).is_some_and(|visit| visit.index.is_some()) {
+      continue;
+    }
+
+    let mut calls = vec![(root, 0usize)];
+    Visit::enter(&mut visits, root, &mut next_index, &mut stack);
+    while let Some((node, next)) = calls.last_mut() {
+      let node = *node;
+      if let Some(&successor) = graph.successors(node).get(*next) {
+        *next = next.saturating_add(1);
+        match visits
+          .get(successor)
+          .map(|visit| (visit.index, visit.on_stack))
+        {
+          Some((None, _)) => {
+            Visit::enter(&mut visits, successor, &mut next_index, &mut stack);
+            calls.push((successor, 0));
+          }
+          Some((Some(index), true)) => Visit::lower(&mut visits, node, index),
+          _ => {}
+        }
+        continue;
+      }
+
+      calls.pop();
+      let Some(&Visit {
+        index: Some(index),
+        low,
+        ..
+      }) = visits.get(node)
+      else {
+        continue;
+      };
+      if let Some(&(parent, _)) = calls.last() {
+        Visit::lower(&mut visits, parent, low);
+      }
+      if low == index {
+        let mut component = Vec::new();
+        while let Some(member) = stack.pop() {
+          if let Some(visit) = visits.get_mut(member) {
+            visit.on_stack = false;
+          }
+          component.push(member);
+          if member == node {
+            break;
+          }
+        }
+        components.push(graph.resolve(component));
+      }
+    }
+  }
+
+  components
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Visit {
+  index: Option<usize>,
+  low: usize,
+  on_stack: bool,
+}
+
+impl Visit {
+  fn enter(
+    visits: &mut [Visit],
+    node: usize,
+    next_index: &mut usize,
+    stack: &mut Vec<usize>,
+  ) {
+    if let Some(visit) = visits.get_mut(node) {
+      *visit = Visit {
+        index: Some(*next_index),
+        low: *next_index,
+        on_stack: true,
+      };
+      *next_index = next_index.saturating_add(1);
+      stack.push(node);
+    }
+  }
+
+  fn lower(visits: &mut [Visit], node: usize, low: usize) {
+    if let Some(visit) = visits.get_mut(node) {
+      visit.low = visit.low.min(low);
+    }
+  }
+}
+
+/// Nodes numbered in the order they first show up.
+struct Indexed<N> {
+  nodes: Vec<N>,
+  successors: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Indexed<N> {
+  fn new(
+    nodes: impl IntoIterator<Item = N>,
+    edges: impl IntoIterator<Item = (N, N)>,
+  ) -> Self {
+    let mut indexed = Self {
+      nodes: Vec::new(),
+      successors: Vec::new(),
+    };
+    let mut indices = HashMap::new();
+
+    for node in nodes {
+      indexed.index(&mut indices, node);
+    }
+    for (from, to) in edges {
+      let from = indexed.index(&mut indices, from);
+      let to = indexed.index(&mut indices, to);
+      if let Some(successors) = indexed.successors.get_mut(from) {
+        successors.push(to);
+      }
+    }
+
+    indexed
+  }
+
+  fn index(&mut self, indices: &mut HashMap<N, usize>, node: N) -> usize {
+    *indices.entry(node.clone()).or_insert_with(|| {
+      self.nodes.push(node);
+      self.successors.push(Vec::new());
+      self.nodes.len().saturating_sub(1)
+    })
+  }
+
+  fn len(&self) -> usize {
+    self.nodes.len()
+  }
+
+  fn successors(&self, node: usize) -> &[usize] {
+    self.successors.get(node).map_or(&[], Vec::as_slice)
+  }
+
+  fn resolve(&self, indices: Vec<usize>) -> Vec<N> {
+    indices
+      .into_iter()
+      .filter_map(|index| self.nodes.get(index).cloned())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sorts_or_finds_cycle() {
+    let edges = [(3, 1), (1, 2), (3, 2)];
+
+    assert_eq!(toposort([1, 2, 3, 4], edges), Ok(vec![3, 4, 1, 2]));
+    assert_eq!(
+      toposort([0], edges.into_iter().chain([(2, 3)])),
+      Err(vec![1, 2, 3])
+    );
+  }
+
+  #[test]
+  fn finds_components() {
+    let edges = [(1, 2), (2, 1), (2, 3), (3, 4), (4, 3), (5, 5)];
+
+    let components = strongly_connected([1, 2, 3, 4, 5], edges)
+      .into_iter()
+      .map(|mut component| {
+        component.sort();
+        component
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(components, [vec![3, 4], vec![1, 2], vec![5]]);
+  }
+}
@@ -0,0 +1,142 @@
+/// Disjoint sets over the indices `0..len`.
+///
+/// Indices past the end are left on their own.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct UnionFind {
+  parents: Vec<usize>,
+  ranks: Vec<u8>,
+  sizes: Vec<usize>,
+  components: usize,
+}
+
+impl UnionFind {
+  /// Every index starts in a set of its own.
+  pub fn new(len: usize) -> Self {
+    Self {
+      parents: (0..len).collect(),
+      ranks: vec![0; len],
+      sizes: vec![1; len],
+      components: len,
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.parents.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.parents.is_empty()
+  }
+
+  /// Number of disjoint sets.
+  pub fn components(&self) -> usize {
+    self.components
+  }
+
+  /// Representative of the set holding `index`.
+  pub fn find(&mut self, index: usize) -> usize {
+    let mut root = index;
+    while let Some(&parent) = self.parents.get(root) {
+      if parent == root {
+        break;
+      }
+      root = parent;
+    }
+
+    let mut current = index;
+    while let Some(parent) = self.parents.get_mut(current) {
+      if *parent == root {
+        break;
+      }
+      current = std::mem::replace(parent, root);
+    }
+
+    root
+  }
+
+  /// Whether the sets of `lhs` and `rhs` were apart before.
+  pub fn union(&mut self, lhs: usize, rhs: usize) -> bool {
+    let lhs = self.find(lhs);
+    let rhs = self.find(rhs);
+    if lhs == rhs || lhs >= self.len() || rhs >= self.len() {
+      return false;
+    }
+
+    let rank = |index| self.ranks.get(index).copied().unwrap_or_default();
+    let (root, child) = if rank(lhs) < rank(rhs) {
+      (rhs, lhs)
+    } else {
+      (lhs, rhs)
+    };
+    let tied = rank(root) == rank(child);
+
+    if let Some(parent) = self.parents.get_mut(child) {
+      *parent = root;
+    }
+    if let Some(rank) = self.ranks.get_mut(root).filter(|_| tied) {
+      *rank = rank.saturating_add(1);
+    }
+    let child_size = self.sizes.get(child).copied().unwrap_or_default();
+    if let Some(size) = self.sizes.get_mut(root) {
+      *size = size.saturating_add(child_size);
+    }
+    self.components = self.components.saturating_sub(1);
+
+    true
+  }
+
+  pub fn connected(&mut self, lhs: usize, rhs: usize) -> bool {
+    self.find(lhs) == self.find(rhs)
+  }
+
+  /// Size of the set holding `index`.
+  pub fn size(&mut self, index: usize) -> usize {
+    let root = self.find(index);
+    self.sizes.get(root).copied().unwrap_or(1)
+  }
+
+  /// Members of every set, sets ordered by their smallest member.
+  pub fn groups(&mut self) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of_root = vec![None::<usize>; self.len()];
+    for index in 0..self.len() {
+      let root = self.find(index);
+      let Some(group) = group_of_root.get_mut(root) else {
+        continue;
+      };
+      match group {
+        Some(group) => {
+          if let Some(members) = groups.get_mut(*group) {
+            members.push(index);
+          }
+        }
+        None => {
+          *group = Some(groups.len());
+          groups.push(vec![index]);
+        }
+      }
+    }
+    groups
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn merges_sets() {
+    let mut sets = UnionFind::new(6);
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 1));
+    assert!(!sets.union(0, 2));
+    assert!(sets.union(4, 5));
+    assert!(!sets.union(4, 6));
+
+    assert!(sets.connected(0, 2));
+    assert!(!sets.connected(2, 3));
+    assert_eq!((sets.components(), sets.size(1), sets.size(3)), (3, 3, 1));
+    assert_eq!(sets.groups(), [vec![0, 1, 2], vec![3], vec![4, 5]]);
+  }
+}
@@ -0,0 +1,38 @@
+use clap::Parser;
+
+use crate::{
+  input::{self, InputArgs},
+  output::{self, Format},
+  puzzle::{Part, Puzzle},
+};
+
+#[derive(Debug, Clone, Parser)]
+struct Args {
+  #[command(flatten)]
+  input: InputArgs,
+
+  /// Solve only this part
+  #[arg(short, long)]
+  part: Option<Part>,
+
+  /// How to print the answers
+  #[arg(short, long, value_enum, default_value_t)]
+  format: Format,
+}
+
+/// Runs a single puzzle from the command line of its own binary.
+pub fn main(puzzle: &Puzzle) -> anyhow::Result<()> {
+  let args = Args::parse();
+  let input = input::resolve(&args.input, input::default_path(puzzle))?;
+
+  let reports =
+    output::print(puzzle, &input, &Part::or_all(args.part), args.format);
+
+  let failed = reports.iter().any(|report| report.answer.is_err());
+
+  if failed {
+    return Err(anyhow::anyhow!("Some parts failed"));
+  }
+
+  Ok(())
+}
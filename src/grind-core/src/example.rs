@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Context;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::puzzle::{Part, Puzzle};
+
+/// Puzzle example kept as `<name>.txt` with its answers in `<name>.toml`.
+#[derive(Debug, Clone)]
+pub struct Example {
+  pub name: String,
+  pub input: String,
+  pub expected: Expected,
+  pub parameters: Parameters,
+}
+
+/// Answers file of an example.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+  #[serde(default)]
+  part1: Option<String>,
+  #[serde(default)]
+  part2: Option<String>,
+  #[serde(default)]
+  parameters: Parameters,
+}
+
+/// Answers of an example, parts without one are not checked.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+  #[serde(default)]
+  pub part1: Option<String>,
+  #[serde(default)]
+  pub part2: Option<String>,
+}
+
+/// Values an example changes about its puzzle, such as the size of a grid,
+/// kept under `[parameters]` in its answers file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Parameters(BTreeMap<String, toml::Value>);
+
+impl Parameters {
+  /// Value of the parameter `name` or `default` when it isn't set.
+  pub fn get_or<T: DeserializeOwned>(
+    &self,
+    name: &str,
+    default: T,
+  ) -> anyhow::Result<T> {
+    match self.0.get(name) {
+      Some(value) => value
+        .clone()
+        .try_into()
+        .with_context(|| format!("Invalid parameter {name} = {value}")),
+      None => Ok(default),
+    }
+  }
+}
+
+impl Expected {
+  pub fn get(&self, part: Part) -> Option<&str> {
+    match part {
+      Part::One => self.part1.as_deref(),
+      Part::Two => self.part2.as_deref(),
+    }
+  }
+}
+
+/// Loads every example in `dir` sorted by name.
+pub fn load(dir: &Path) -> anyhow::Result<Vec<Example>> {
+  let mut inputs = Vec::new();
+  for entry in std::fs::read_dir(dir).with_context(|| {
+    format!("Failed reading examples from {}", dir.display())
+  })? {
+    let path = entry?.path();
+    if path
+      .extension()
+      .is_some_and(|extension| extension == INPUT_EXTENSION)
+    {
+      inputs.push(path);
+    }
+  }
+  inputs.sort();
+
+  inputs
+    .into_iter()
+    .map(|input| {
+      let name = input
+        .file_stem()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+      let answers = input.with_extension(ANSWERS_EXTENSION);
+
+      let text = std::fs::read_to_string(&answers).with_context(|| {
+        format!("Failed reading answers from {}", answers.display())
+      })?;
+      let fixture = toml::from_str::<Fixture>(&text)
+        .with_context(|| format!("Invalid answers in {}", answers.display()))?;
+
+      Ok(Example {
+        name,
+        input: crate::input::read_file(&input)?,
+        expected: Expected {
+          part1: fixture.part1,
+          part2: fixture.part2,
+        },
+        parameters: fixture.parameters,
+      })
+    })
+    .collect()
+}
+
+/// Solves every example in `dir` and fails listing each wrong answer.
+pub fn check(puzzle: &Puzzle, dir: impl AsRef<Path>) -> anyhow::Result<()> {
+  let dir = dir.as_ref();
+  let examples = load(dir)?;
+  if examples.is_empty() {
+    return Err(anyhow::anyhow!("No examples in {}", dir.display()));
+  }
+
+  let mut failures = Vec::new();
+  for example in examples {
+    let parts = Part::ALL
+      .into_iter()
+      .filter(|&part| example.expected.get(part).is_some())
+      .collect::<Vec<_>>();
+
+    for report in puzzle.run_with(&example.input, &example.parameters, &parts) {
+      let expected = example.expected.get(report.part).unwrap_or_default();
+      match &report.answer {
+        Ok(answer) if answer == expected => {}
+        Ok(answer) => failures.push(format!(
+          "{} part {}: expected {expected}, got {answer}",
+          example.name, report.part
+        )),
+        Err(err) => failures.push(format!(
+          "{} part {}: expected {expected}, failed: {err:#}",
+          example.name, report.part
+        )),
+      }
+    }
+  }
+
+  if !failures.is_empty() {
+    return Err(anyhow::anyhow!(
+      "Wrong answers for {}/{} examples:\n{}",
+      puzzle.year,
+      puzzle.day,
+      failures.join("\n")
+    ));
+  }
+
+  Ok(())
+}
+
+/// Generates a test checking `$puzzle` against the examples of the crate,
+/// parsing each with the `[parameters]` of its answers file.
+#[macro_export]
+macro_rules! examples {
+  ($puzzle:ident) => {
+    #[cfg(test)]
+    mod examples {
+      #[test]
+      fn examples() -> anyhow::Result<()> {
+        $crate::example::check(
+          &super::$puzzle,
+          concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        )
+      }
+    }
+  };
+}
+
+const INPUT_EXTENSION: &str = "txt";
+const ANSWERS_EXTENSION: &str = "toml";
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reads_parameters() -> anyhow::Result<()> {
+    let fixture = toml::from_str::<Fixture>(
+      "part1 = \"12\"\n[parameters]\nwidth = 11\nname = \"small\"\n",
+    )?;
+
+    assert_eq!(fixture.part1.as_deref(), Some("12"));
+    assert_eq!(fixture.parameters.get_or("width", 101)?, 11);
+    assert_eq!(fixture.parameters.get_or("height", 103)?, 103);
+    assert!(fixture.parameters.get_or("name", 0).is_err());
+    assert!(toml::from_str::<Fixture>("part3 = \"1\"").is_err());
+
+    Ok(())
+  }
+}
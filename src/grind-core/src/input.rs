@@ -0,0 +1,95 @@
+#[cfg(not(unix))]
+use std::io::IsTerminal;
+use std::{
+  io::Read,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::puzzle::{Day, Puzzle, Year};
+
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct InputArgs {
+  /// Puzzle input file, `-` reads standard input
+  #[arg(short, long, value_name = "PATH")]
+  pub input: Option<PathBuf>,
+}
+
+/// Loads the puzzle input given on the command line, piped through standard
+/// input or, when neither is given, from `default`.
+pub fn resolve(
+  args: &InputArgs,
+  default: impl AsRef<Path>,
+) -> anyhow::Result<String> {
+  match &args.input {
+    Some(path) if path.as_os_str() == STDIN_PATH => read_stdin(),
+    Some(path) => read_file(path),
+    None => {
+      if stdin_is_redirected() {
+        let input = read_stdin()?;
+        if !input.trim().is_empty() {
+          return Ok(input);
+        }
+      }
+
+      read_file(default.as_ref())
+    }
+  }
+}
+
+/// Input fetched into the cache when there is one, the committed input
+/// otherwise.
+pub fn default_path(puzzle: &Puzzle) -> PathBuf {
+  cache_path(puzzle.year, puzzle.day)
+    .filter(|path| path.exists())
+    .unwrap_or_else(|| PathBuf::from(puzzle.input))
+}
+
+/// Where fetched inputs are kept, outside of the repository.
+pub fn cache_dir() -> Option<PathBuf> {
+  std::env::var_os(CACHE_DIR_ENV)
+    .map(PathBuf::from)
+    .or_else(|| dirs::cache_dir().map(|dir| dir.join(CACHE_DIR_NAME)))
+}
+
+pub fn cache_path(year: Year, day: Day) -> Option<PathBuf> {
+  cache_dir()
+    .map(|dir| dir.join(year.to_string()).join(format!("{day:02}.txt")))
+}
+
+pub fn read_file(path: &Path) -> anyhow::Result<String> {
+  std::fs::read_to_string(path)
+    .with_context(|| format!("Failed reading input from {}", path.display()))
+}
+
+// NOTE: only pipes and files count so that an inherited terminal or socket
+// does not block waiting for input that never comes
+#[cfg(unix)]
+fn stdin_is_redirected() -> bool {
+  use std::os::unix::fs::FileTypeExt;
+
+  std::fs::metadata(STDIN_DEVICE_PATH)
+    .is_ok_and(|metadata| metadata.is_file() || metadata.file_type().is_fifo())
+}
+
+#[cfg(not(unix))]
+fn stdin_is_redirected() -> bool {
+  !std::io::stdin().is_terminal()
+}
+
+fn read_stdin() -> anyhow::Result<String> {
+  let mut input = String::new();
+  std::io::stdin()
+    .read_to_string(&mut input)
+    .context("Failed reading input from standard input")?;
+  Ok(input)
+}
+
+const STDIN_PATH: &str = "-";
+
+const CACHE_DIR_ENV: &str = "GRIND_CACHE_DIR";
+const CACHE_DIR_NAME: &str = "grind";
+
+#[cfg(unix)]
+const STDIN_DEVICE_PATH: &str = "/dev/stdin";
@@ -0,0 +1,19 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+pub mod cli;
+pub mod example;
+pub mod input;
+pub mod output;
+pub mod puzzle;
+pub mod solution;
+
+pub use cli::main;
+pub use example::Parameters;
+pub use output::Diagnostics;
+pub use puzzle::{Day, Part, Puzzle, Year};
+pub use solution::Solution;
@@ -0,0 +1,148 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::Serialize;
+
+use crate::puzzle::{Day, Part, Puzzle, Report, Year};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+  /// One line per part for people
+  #[default]
+  Text,
+  /// One JSON object per puzzle for scripts
+  Json,
+}
+
+/// Day specific findings keyed by name, shown in the JSON output.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(transparent)]
+pub struct Diagnostics(BTreeMap<String, serde_json::Value>);
+
+impl Diagnostics {
+  pub fn with(
+    mut self,
+    name: &str,
+    value: impl Serialize,
+  ) -> anyhow::Result<Self> {
+    self.0.insert(name.to_owned(), serde_json::to_value(value)?);
+    Ok(self)
+  }
+}
+
+/// Everything known about one run of a puzzle in a stable shape.
+#[derive(Debug, Clone, Serialize)]
+pub struct Output {
+  pub year: Year,
+  pub day: Day,
+  pub parse_ns: u64,
+  pub parts: Vec<PartOutput>,
+  pub diagnostics: Diagnostics,
+  pub diagnostics_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartOutput {
+  pub part: u8,
+  pub answer: Option<String>,
+  pub error: Option<String>,
+  pub solve_ns: u64,
+}
+
+impl Output {
+  pub fn new(
+    puzzle: &Puzzle,
+    reports: &[Report],
+    diagnostics: anyhow::Result<Diagnostics>,
+  ) -> Self {
+    let (diagnostics, diagnostics_error) = match diagnostics {
+      Ok(diagnostics) => (diagnostics, None),
+      Err(err) => (Diagnostics::default(), Some(format!("{err:#}"))),
+    };
+
+    Self {
+      year: puzzle.year,
+      day: puzzle.day,
+      parse_ns: reports.first().map_or(0, |report| nanos(report.parse)),
+      parts: reports
+        .iter()
+        .map(|report| PartOutput {
+          part: report.part.number(),
+          answer: report.answer.as_ref().ok().cloned(),
+          error: report.answer.as_ref().err().map(|err| format!("{err:#}")),
+          solve_ns: nanos(report.solve),
+        })
+        .collect(),
+      diagnostics,
+      diagnostics_error,
+    }
+  }
+
+  /// Output of a puzzle that could not even start, like on missing input.
+  pub fn failed(puzzle: &Puzzle, parts: &[Part], err: &anyhow::Error) -> Self {
+    let error = format!("{err:#}");
+
+    Self {
+      year: puzzle.year,
+      day: puzzle.day,
+      parse_ns: 0,
+      parts: parts
+        .iter()
+        .map(|part| PartOutput {
+          part: part.number(),
+          answer: None,
+          error: Some(error.clone()),
+          solve_ns: 0,
+        })
+        .collect(),
+      diagnostics: Diagnostics::default(),
+      diagnostics_error: Some(error),
+    }
+  }
+}
+
+impl Display for Output {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+    write!(f, "{json}")
+  }
+}
+
+/// Solves `parts` and prints them in `format`.
+pub fn print(
+  puzzle: &Puzzle,
+  input: &str,
+  parts: &[Part],
+  format: Format,
+) -> Vec<Report> {
+  match format {
+    Format::Text => {
+      let reports = puzzle.run(input, parts);
+      for report in reports.iter() {
+        println!("{report}");
+      }
+      reports
+    }
+    Format::Json => {
+      let (reports, diagnostics) = puzzle.diagnose(input, parts);
+      println!("{}", Output::new(puzzle, &reports, diagnostics));
+      reports
+    }
+  }
+}
+
+/// Prints why `parts` could not even be tried in `format`.
+pub fn print_failed(
+  puzzle: &Puzzle,
+  parts: &[Part],
+  err: &anyhow::Error,
+  format: Format,
+) {
+  match format {
+    Format::Text => println!("{}/{}: failed: {err:#}", puzzle.year, puzzle.day),
+    Format::Json => println!("{}", Output::failed(puzzle, parts, err)),
+  }
+}
+
+fn nanos(duration: std::time::Duration) -> u64 {
+  u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,240 @@
+use std::{
+  any::Any,
+  fmt::Display,
+  str::FromStr,
+  time::{Duration, Instant},
+};
+
+use crate::{example::Parameters, output::Diagnostics, solution::Solution};
+
+pub type Year = u16;
+pub type Day = u8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+  One,
+  Two,
+}
+
+impl Part {
+  pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+  pub fn number(self) -> u8 {
+    match self {
+      Part::One => 1,
+      Part::Two => 2,
+    }
+  }
+
+  pub fn or_all(part: Option<Part>) -> Vec<Part> {
+    part.map_or_else(|| Part::ALL.to_vec(), |part| vec![part])
+  }
+}
+
+impl Display for Part {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.number())
+  }
+}
+
+impl FromStr for Part {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim() {
+      "1" => Ok(Part::One),
+      "2" => Ok(Part::Two),
+      _ => Err(anyhow::anyhow!("Part must be 1 or 2, got {s:?}")),
+    }
+  }
+}
+
+/// Type erased [`Solution`] so days can be listed and run side by side.
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+  pub year: Year,
+  pub day: Day,
+  /// Path to the `input.txt` committed next to the solution
+  pub input: &'static str,
+  parse: fn(&str, &Parameters) -> anyhow::Result<Parsed>,
+  part1: fn(&Parsed) -> anyhow::Result<String>,
+  part2: fn(&Parsed) -> anyhow::Result<String>,
+  diagnostics: fn(&Parsed) -> anyhow::Result<Diagnostics>,
+}
+
+impl Puzzle {
+  pub const fn new<S: Solution>(input: &'static str) -> Self
+  where
+    S::Input: 'static,
+  {
+    Self {
+      year: S::YEAR,
+      day: S::DAY,
+      input,
+      parse: parse::<S>,
+      part1: part1::<S>,
+      part2: part2::<S>,
+      diagnostics: diagnostics::<S>,
+    }
+  }
+
+  pub fn parse(&self, input: &str) -> anyhow::Result<Parsed> {
+    self.parse_with(input, &Parameters::default())
+  }
+
+  pub fn parse_with(
+    &self,
+    input: &str,
+    parameters: &Parameters,
+  ) -> anyhow::Result<Parsed> {
+    (self.parse)(input, parameters)
+  }
+
+  pub fn solve(&self, parsed: &Parsed, part: Part) -> anyhow::Result<String> {
+    match part {
+      Part::One => (self.part1)(parsed),
+      Part::Two => (self.part2)(parsed),
+    }
+  }
+
+  /// Parses the input once and solves each of the parts.
+  pub fn run(&self, input: &str, parts: &[Part]) -> Vec<Report> {
+    self.run_with(input, &Parameters::default(), parts)
+  }
+
+  /// Like [`Puzzle::run`] with the `parameters` of an example.
+  pub fn run_with(
+    &self,
+    input: &str,
+    parameters: &Parameters,
+    parts: &[Part],
+  ) -> Vec<Report> {
+    let (parsed, parse) = self.timed_parse(input, parameters);
+    self.solve_all(&parsed, parse, parts)
+  }
+
+  /// Like [`Puzzle::run`] but also collects the diagnostics of the solution.
+  pub fn diagnose(
+    &self,
+    input: &str,
+    parts: &[Part],
+  ) -> (Vec<Report>, anyhow::Result<Diagnostics>) {
+    let (parsed, parse) = self.timed_parse(input, &Parameters::default());
+    let reports = self.solve_all(&parsed, parse, parts);
+    let diagnostics = match &parsed {
+      Ok(parsed) => (self.diagnostics)(parsed),
+      Err(err) => Err(anyhow::anyhow!("Failed parsing input: {err:#}")),
+    };
+
+    (reports, diagnostics)
+  }
+
+  fn timed_parse(
+    &self,
+    input: &str,
+    parameters: &Parameters,
+  ) -> (anyhow::Result<Parsed>, Duration) {
+    let start = Instant::now();
+    let parsed = self.parse_with(input, parameters);
+    (parsed, start.elapsed())
+  }
+
+  fn solve_all(
+    &self,
+    parsed: &anyhow::Result<Parsed>,
+    parse: Duration,
+    parts: &[Part],
+  ) -> Vec<Report> {
+    parts
+      .iter()
+      .map(|&part| {
+        let start = Instant::now();
+        let answer = match parsed {
+          Ok(parsed) => self.solve(parsed, part),
+          Err(err) => Err(anyhow::anyhow!("Failed parsing input: {err:#}")),
+        };
+        let solve = start.elapsed();
+
+        Report {
+          year: self.year,
+          day: self.day,
+          part,
+          answer,
+          parse,
+          solve,
+        }
+      })
+      .collect()
+  }
+}
+
+/// Parsed input of a [`Puzzle`].
+#[derive(Debug)]
+pub struct Parsed(Box<dyn Any>);
+
+fn parse<S: Solution>(
+  input: &str,
+  parameters: &Parameters,
+) -> anyhow::Result<Parsed>
+where
+  S::Input: 'static,
+{
+  Ok(Parsed(Box::new(S::parse_with(input, parameters)?)))
+}
+
+fn part1<S: Solution>(parsed: &Parsed) -> anyhow::Result<String>
+where
+  S::Input: 'static,
+{
+  Ok(S::part1(downcast::<S>(parsed)?)?.to_string())
+}
+
+fn part2<S: Solution>(parsed: &Parsed) -> anyhow::Result<String>
+where
+  S::Input: 'static,
+{
+  Ok(S::part2(downcast::<S>(parsed)?)?.to_string())
+}
+
+fn diagnostics<S: Solution>(parsed: &Parsed) -> anyhow::Result<Diagnostics>
+where
+  S::Input: 'static,
+{
+  S::diagnostics(downcast::<S>(parsed)?)
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> anyhow::Result<&S::Input>
+where
+  S::Input: 'static,
+{
+  parsed.0.downcast_ref::<S::Input>().ok_or_else(|| {
+    anyhow::anyhow!("Input was not parsed for {}/{}", S::YEAR, S::DAY)
+  })
+}
+
+#[derive(Debug)]
+pub struct Report {
+  pub year: Year,
+  pub day: Day,
+  pub part: Part,
+  pub answer: anyhow::Result<String>,
+  pub parse: Duration,
+  pub solve: Duration,
+}
+
+impl Report {
+  pub fn elapsed(&self) -> Duration {
+    self.parse.saturating_add(self.solve)
+  }
+}
+
+impl Display for Report {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}/{}/{}: ", self.year, self.day, self.part)?;
+    match &self.answer {
+      Ok(answer) => write!(f, "{answer}")?,
+      Err(err) => write!(f, "failed: {err:#}")?,
+    }
+    write!(f, " ({:.2?})", self.elapsed())
+  }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use crate::{
+  example::Parameters,
+  output::Diagnostics,
+  puzzle::{Day, Year},
+};
+
+/// A day solved as separate parsing and answering steps.
+pub trait Solution {
+  const YEAR: Year;
+  const DAY: Day;
+
+  type Input;
+  type Part1: Display;
+  type Part2: Display;
+
+  fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+  /// Like [`Solution::parse`] for examples that change some of the
+  /// `parameters` of the puzzle.
+  fn parse_with(
+    input: &str,
+    _parameters: &Parameters,
+  ) -> anyhow::Result<Self::Input> {
+    Self::parse(input)
+  }
+
+  fn part1(input: &Self::Input) -> anyhow::Result<Self::Part1>;
+
+  fn part2(input: &Self::Input) -> anyhow::Result<Self::Part2>;
+
+  /// Day specific findings worth looking at next to the answers.
+  fn diagnostics(_input: &Self::Input) -> anyhow::Result<Diagnostics> {
+    Ok(Diagnostics::default())
+  }
+}
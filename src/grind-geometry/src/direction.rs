@@ -0,0 +1,126 @@
+use crate::Vector2;
+
+/// Heading on a grid where north is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+  North,
+  East,
+  South,
+  West,
+}
+
+impl Direction {
+  /// Clockwise from north.
+  pub const ALL: [Self; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+  ];
+
+  pub fn turn_left(self) -> Self {
+    match self {
+      Direction::North => Direction::West,
+      Direction::East => Direction::North,
+      Direction::South => Direction::East,
+      Direction::West => Direction::South,
+    }
+  }
+
+  pub fn turn_right(self) -> Self {
+    match self {
+      Direction::North => Direction::East,
+      Direction::East => Direction::South,
+      Direction::South => Direction::West,
+      Direction::West => Direction::North,
+    }
+  }
+
+  pub fn invert(self) -> Self {
+    match self {
+      Direction::North => Direction::South,
+      Direction::East => Direction::West,
+      Direction::South => Direction::North,
+      Direction::West => Direction::East,
+    }
+  }
+
+  /// Quarter turns needed to face `other`, at most two.
+  pub fn turns(self, other: Self) -> usize {
+    if self == other {
+      0
+    } else if self.invert() == other {
+      2
+    } else {
+      1
+    }
+  }
+
+  pub fn offset(self) -> Vector2 {
+    match self {
+      Direction::North => Vector2::NORTH,
+      Direction::East => Vector2::EAST,
+      Direction::South => Vector2::SOUTH,
+      Direction::West => Vector2::WEST,
+    }
+  }
+}
+
+/// Set of directions packed into a byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Directions(u8);
+
+impl Directions {
+  pub const NONE: Self = Self(0);
+
+  /// Whether `direction` was not in the set yet.
+  pub fn insert(&mut self, direction: Direction) -> bool {
+    let inserted = !self.contains(direction);
+    self.0 |= bit(direction);
+    inserted
+  }
+
+  pub fn remove(&mut self, direction: Direction) -> bool {
+    let removed = self.contains(direction);
+    self.0 &= !bit(direction);
+    removed
+  }
+
+  pub fn contains(self, direction: Direction) -> bool {
+    self.0 & bit(direction) != 0
+  }
+
+  pub fn len(self) -> usize {
+    self.0.count_ones() as usize
+  }
+
+  pub fn is_empty(self) -> bool {
+    self.0 == 0
+  }
+
+  /// Members clockwise from north.
+  pub fn iter(self) -> impl Iterator<Item = Direction> {
+    Direction::ALL
+      .into_iter()
+      .filter(move |&direction| self.contains(direction))
+  }
+}
+
+impl FromIterator<Direction> for Directions {
+  fn from_iter<T: IntoIterator<Item = Direction>>(iter: T) -> Self {
+    let mut directions = Self::NONE;
+    for direction in iter {
+      directions.insert(direction);
+    }
+    directions
+  }
+}
+
+fn bit(direction: Direction) -> u8 {
+  match direction {
+    Direction::North => 0b0001,
+    Direction::East => 0b0010,
+    Direction::South => 0b0100,
+    Direction::West => 0b1000,
+  }
+}
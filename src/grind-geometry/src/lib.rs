@@ -0,0 +1,70 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+pub mod direction;
+pub mod linear;
+pub mod plane;
+
+pub use direction::{Direction, Directions};
+pub use linear::{Point2, Point3, Vector2, Vector3};
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn measures_distances() {
+    let origin = Point3::new(0, 0, 0);
+    let point = Point3::new(3, 4, 12);
+
+    assert_eq!(origin.manhattan(point), 19);
+    assert_eq!(origin.chebyshev(point), 12);
+    assert_eq!(origin.euclidean_squared(point), 169);
+    assert_eq!(origin.diff(point).map(Vector3::manhattan), Some(19));
+  }
+
+  #[test]
+  fn checks_arithmetic() {
+    let point = Point2::new(1, 0);
+
+    assert_eq!(point.checked_add(Vector2::NORTH), None);
+    assert_eq!(point.checked_add(Vector2::WEST), Some(Point2::new(0, 0)));
+    assert_eq!(point.checked_sub(Vector2::SOUTH), None);
+    assert_eq!(Vector2::new(isize::MIN, 0).checked_neg(), None);
+    assert_eq!(Vector2::try_from(point), Ok(Vector2::EAST));
+    assert_eq!(Point2::try_from(Vector2::SOUTH), Ok(Point2::new(0, 1)));
+    assert!(Point2::try_from(Vector2::WEST).is_err());
+  }
+
+  #[test]
+  fn turns_around() {
+    for direction in Direction::ALL {
+      assert_eq!(direction.turn_left().turn_right(), direction);
+      assert_eq!(direction.turn_right().turn_right(), direction.invert());
+      assert_eq!(direction.turns(direction.invert()), 2);
+      assert_eq!(
+        direction.offset().checked_add(direction.invert().offset()),
+        Some(Vector2::default())
+      );
+    }
+  }
+
+  #[test]
+  fn packs_directions() {
+    let mut directions = Directions::NONE;
+
+    assert!(directions.insert(Direction::West));
+    assert!(!directions.insert(Direction::West));
+    directions.insert(Direction::North);
+    assert_eq!(
+      directions.iter().collect::<Vec<_>>(),
+      [Direction::North, Direction::West]
+    );
+    assert!(directions.remove(Direction::North));
+    assert_eq!(directions.len(), 1);
+  }
+}
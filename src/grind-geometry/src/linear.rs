@@ -0,0 +1,145 @@
+use std::{fmt::Display, num::TryFromIntError};
+
+/// Point and vector types over the given axes.
+macro_rules! linear {
+  ($point:ident, $vector:ident, $($axis:ident),+) => {
+    /// Unsigned location, on grids `y` grows downwards.
+    #[derive(
+      Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+    )]
+    pub struct $point {
+      $(pub $axis: usize),+
+    }
+
+    /// Signed displacement between two points.
+    #[derive(
+      Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+    )]
+    pub struct $vector {
+      $(pub $axis: isize),+
+    }
+
+    impl $point {
+      pub const fn new($($axis: usize),+) -> Self {
+        Self { $($axis),+ }
+      }
+
+      /// Moves by `vector` unless that leaves the non-negative quadrant.
+      pub fn checked_add(self, vector: $vector) -> Option<Self> {
+        Some(Self {
+          $($axis: self.$axis.checked_add_signed(vector.$axis)?),+
+        })
+      }
+
+      pub fn checked_sub(self, vector: $vector) -> Option<Self> {
+        self.checked_add(vector.checked_neg()?)
+      }
+
+      /// Vector that leads from `self` to `other`.
+      pub fn diff(self, other: Self) -> Option<$vector> {
+        Some($vector {
+          $($axis: isize::try_from(other.$axis)
+            .ok()?
+            .checked_sub(isize::try_from(self.$axis).ok()?)?),+
+        })
+      }
+
+      pub fn manhattan(self, other: Self) -> usize {
+        0usize$(.saturating_add(self.$axis.abs_diff(other.$axis)))+
+      }
+
+      pub fn chebyshev(self, other: Self) -> usize {
+        0usize$(.max(self.$axis.abs_diff(other.$axis)))+
+      }
+
+      pub fn euclidean_squared(self, other: Self) -> usize {
+        0usize$(.saturating_add(square(self.$axis.abs_diff(other.$axis))))+
+      }
+    }
+
+    impl $vector {
+      pub const fn new($($axis: isize),+) -> Self {
+        Self { $($axis),+ }
+      }
+
+      pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self {
+          $($axis: self.$axis.checked_add(other.$axis)?),+
+        })
+      }
+
+      pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self {
+          $($axis: self.$axis.checked_sub(other.$axis)?),+
+        })
+      }
+
+      pub fn checked_mul(self, factor: isize) -> Option<Self> {
+        Some(Self {
+          $($axis: self.$axis.checked_mul(factor)?),+
+        })
+      }
+
+      pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+          $($axis: self.$axis.checked_neg()?),+
+        })
+      }
+
+      pub fn manhattan(self) -> usize {
+        0usize$(.saturating_add(self.$axis.unsigned_abs()))+
+      }
+
+      pub fn chebyshev(self) -> usize {
+        0usize$(.max(self.$axis.unsigned_abs()))+
+      }
+
+      pub fn euclidean_squared(self) -> usize {
+        0usize$(.saturating_add(square(self.$axis.unsigned_abs())))+
+      }
+    }
+
+    /// Vector from the origin to the point.
+    impl TryFrom<$point> for $vector {
+      type Error = TryFromIntError;
+
+      fn try_from(point: $point) -> Result<Self, Self::Error> {
+        Ok(Self {
+          $($axis: isize::try_from(point.$axis)?),+
+        })
+      }
+    }
+
+    /// Point the vector leads to from the origin.
+    impl TryFrom<$vector> for $point {
+      type Error = TryFromIntError;
+
+      fn try_from(vector: $vector) -> Result<Self, Self::Error> {
+        Ok(Self {
+          $($axis: usize::try_from(vector.$axis)?),+
+        })
+      }
+    }
+
+    impl Display for $point {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let axes = [$(self.$axis.to_string()),+];
+        write!(f, "({})", axes.join(", "))
+      }
+    }
+
+    impl Display for $vector {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let axes = [$(format!("{:+}", self.$axis)),+];
+        write!(f, "({})", axes.join(", "))
+      }
+    }
+  };
+}
+
+linear!(Point2, Vector2, x, y);
+linear!(Point3, Vector3, x, y, z);
+
+fn square(value: usize) -> usize {
+  value.saturating_mul(value)
+}
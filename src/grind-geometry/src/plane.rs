@@ -0,0 +1,28 @@
+use crate::Vector2;
+
+impl Vector2 {
+  pub const NORTH: Self = Self::new(0, -1);
+  pub const NORTH_EAST: Self = Self::new(1, -1);
+  pub const EAST: Self = Self::new(1, 0);
+  pub const SOUTH_EAST: Self = Self::new(1, 1);
+  pub const SOUTH: Self = Self::new(0, 1);
+  pub const SOUTH_WEST: Self = Self::new(-1, 1);
+  pub const WEST: Self = Self::new(-1, 0);
+  pub const NORTH_WEST: Self = Self::new(-1, -1);
+
+  /// The four steps sharing an edge, clockwise from north.
+  pub const ORTHOGONAL: [Self; 4] =
+    [Self::NORTH, Self::EAST, Self::SOUTH, Self::WEST];
+
+  /// The eight steps sharing an edge or a corner, clockwise from north.
+  pub const ADJACENT: [Self; 8] = [
+    Self::NORTH,
+    Self::NORTH_EAST,
+    Self::EAST,
+    Self::SOUTH_EAST,
+    Self::SOUTH,
+    Self::SOUTH_WEST,
+    Self::WEST,
+    Self::NORTH_WEST,
+  ];
+}
@@ -0,0 +1,279 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitChar, LitStr};
+
+/// Implements `Tile`, `TryFrom<char>`, `FromStr` and `Display` for an enum of
+/// unit variants each marked with the character it is written as through
+/// `#[tile('#')]`.
+///
+/// The enum also gets `<VARIANT>_CHAR` and `<VARIANT>_STR` constants and a
+/// `LEGEND` pairing every character with its variant name.
+#[proc_macro_derive(Tile, attributes(tile))]
+pub fn derive_tile(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  expand(input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+struct Variant {
+  ident: Ident,
+  char: LitChar,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+  let Data::Enum(data) = &input.data else {
+    return Err(syn::Error::new_spanned(
+      &input.ident,
+      "Tile can only be derived for enums",
+    ));
+  };
+
+  if data.variants.is_empty() {
+    return Err(syn::Error::new_spanned(
+      &input.ident,
+      "Tile needs at least one variant",
+    ));
+  }
+
+  let mut variants = Vec::<Variant>::new();
+  for variant in data.variants.iter() {
+    if !matches!(variant.fields, Fields::Unit) {
+      return Err(syn::Error::new_spanned(
+        variant,
+        "Tile variants can not have fields",
+      ));
+    }
+
+    let mut chars = variant
+      .attrs
+      .iter()
+      .filter(|attr| attr.path().is_ident("tile"))
+      .map(|attr| attr.parse_args::<LitChar>());
+    let char = match (chars.next(), chars.next()) {
+      (Some(char), None) => char?,
+      (None, _) => {
+        return Err(syn::Error::new_spanned(
+          variant,
+          "Tile variants need a #[tile('c')] attribute",
+        ))
+      }
+      (Some(_), Some(_)) => {
+        return Err(syn::Error::new_spanned(
+          variant,
+          "Tile variants need exactly one #[tile('c')] attribute",
+        ))
+      }
+    };
+    if let Some(other) = variants
+      .iter()
+      .find(|other| other.char.value() == char.value())
+    {
+      return Err(syn::Error::new_spanned(
+        &char,
+        format!(
+          "Tile character {:?} is taken by {}",
+          char.value(),
+          other.ident
+        ),
+      ));
+    }
+
+    variants.push(Variant {
+      ident: variant.ident.clone(),
+      char,
+    });
+  }
+
+  let name = &input.ident;
+  let name_str = LitStr::new(&name.to_string(), name.span());
+  let vis = &input.vis;
+  let (impl_generics, type_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  let idents = variants.iter().map(|variant| &variant.ident);
+  let chars = variants.iter().map(|variant| &variant.char);
+  let char_consts = variants
+    .iter()
+    .map(|variant| const_ident(&variant.ident, "CHAR"))
+    .collect::<Vec<_>>();
+  let str_consts = variants
+    .iter()
+    .map(|variant| const_ident(&variant.ident, "STR"))
+    .collect::<Vec<_>>();
+  let strs = variants
+    .iter()
+    .map(|variant| {
+      LitStr::new(&variant.char.value().to_string(), variant.char.span())
+    })
+    .collect::<Vec<_>>();
+  let names = variants
+    .iter()
+    .map(|variant| {
+      LitStr::new(&variant.ident.to_string(), variant.ident.span())
+    })
+    .collect::<Vec<_>>();
+  let idents = idents.collect::<Vec<_>>();
+  let chars = chars.collect::<Vec<_>>();
+
+  Ok(quote! {
+    #[allow(dead_code, reason = "generated for every tile")]
+    impl #impl_generics #name #type_generics #where_clause {
+      #(#vis const #char_consts: char = #chars;)*
+      #(#vis const #str_consts: &'static str = #strs;)*
+
+      /// Every tile character along with the name of its variant.
+      #vis const LEGEND: &'static [(char, &'static str)] =
+        &[#((#chars, #names)),*];
+    }
+
+    impl #impl_generics ::core::convert::TryFrom<char>
+      for #name #type_generics #where_clause
+    {
+      type Error = ::grind_grid::UnknownTile;
+
+      fn try_from(char: char) -> ::core::result::Result<Self, Self::Error> {
+        match char {
+          #(#chars => ::core::result::Result::Ok(Self::#idents),)*
+          _ => ::core::result::Result::Err(::grind_grid::UnknownTile::new(
+            #name_str,
+            char,
+            Self::LEGEND,
+          )),
+        }
+      }
+    }
+
+    impl #impl_generics ::core::str::FromStr for #name #type_generics
+      #where_clause
+    {
+      type Err = ::grind_grid::UnknownTile;
+
+      fn from_str(text: &str) -> ::core::result::Result<Self, Self::Err> {
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+          (::core::option::Option::Some(char), ::core::option::Option::None) => {
+            Self::try_from(char)
+          }
+          _ => ::core::result::Result::Err(::grind_grid::UnknownTile::new(
+            #name_str,
+            text,
+            Self::LEGEND,
+          )),
+        }
+      }
+    }
+
+    impl #impl_generics ::core::fmt::Display for #name #type_generics
+      #where_clause
+    {
+      fn fmt(
+        &self,
+        f: &mut ::core::fmt::Formatter<'_>,
+      ) -> ::core::fmt::Result {
+        f.pad(match self {
+          #(Self::#idents => #strs,)*
+        })
+      }
+    }
+
+    impl #impl_generics ::grind_grid::Tile for #name #type_generics
+      #where_clause
+    {
+      fn from_char(char: char) -> ::grind_grid::__anyhow::Result<Self> {
+        ::core::result::Result::Ok(Self::try_from(char)?)
+      }
+
+      fn to_char(&self) -> char {
+        match self {
+          #(Self::#idents => #chars,)*
+        }
+      }
+    }
+  })
+}
+
+/// `ThickBoxStart` and `CHAR` make `THICK_BOX_START_CHAR`.
+fn const_ident(variant: &Ident, suffix: &str) -> Ident {
+  let mut name = String::new();
+  for (index, char) in variant.to_string().chars().enumerate() {
+    if char.is_uppercase() && index != 0 {
+      name.push('_');
+    }
+    name.extend(char.to_uppercase());
+  }
+  name.push('_');
+  name.push_str(suffix);
+
+  Ident::new(&name, Span::call_site())
+}
+
+#[cfg(test)]
+mod tests {
+  use syn::parse_quote;
+
+  use super::*;
+
+  fn error(input: DeriveInput) -> Option<String> {
+    expand(input).err().map(|err| err.to_string())
+  }
+
+  #[test]
+  fn expands_unit_variants() {
+    let expanded = expand(parse_quote! {
+      enum Cave {
+        #[tile('#')]
+        Rock,
+        #[tile('.')]
+        OpenAir,
+      }
+    })
+    .map(|tokens| tokens.to_string())
+    .unwrap_or_default();
+
+    assert!(expanded.contains("OPEN_AIR_CHAR"));
+    assert!(expanded.contains("ROCK_STR"));
+    assert!(expanded.contains("LEGEND"));
+  }
+
+  #[test]
+  fn rejects_invalid_tiles() {
+    assert_eq!(
+      error(parse_quote! { struct Cave; }).as_deref(),
+      Some("Tile can only be derived for enums")
+    );
+    assert_eq!(
+      error(parse_quote! { enum Cave {} }).as_deref(),
+      Some("Tile needs at least one variant")
+    );
+    assert_eq!(
+      error(parse_quote! { enum Cave { #[tile('#')] Rock(u8) } }).as_deref(),
+      Some("Tile variants can not have fields")
+    );
+    assert_eq!(
+      error(parse_quote! { enum Cave { Rock } }).as_deref(),
+      Some("Tile variants need a #[tile('c')] attribute")
+    );
+    assert_eq!(
+      error(parse_quote! { enum Cave { #[tile('#')] #[tile('x')] Rock } })
+        .as_deref(),
+      Some("Tile variants need exactly one #[tile('c')] attribute")
+    );
+    assert_eq!(
+      error(parse_quote! {
+        enum Cave { #[tile('#')] Rock, #[tile('#')] Wall }
+      })
+      .as_deref(),
+      Some("Tile character '#' is taken by Rock")
+    );
+    assert!(error(parse_quote! { enum Cave { #[tile("#")] Rock } }).is_some());
+  }
+}
@@ -0,0 +1,420 @@
+use std::fmt::Display;
+
+use grind_geometry::{Point2, Vector2};
+
+use crate::Grid;
+
+/// One bit per cell, rows packed into whole words.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitGrid {
+  width: usize,
+  height: usize,
+  stride: usize,
+  words: Vec<Word>,
+}
+
+type Word = u64;
+
+const WORD_BITS: usize = Word::BITS as usize;
+
+impl BitGrid {
+  /// Every bit starts unset.
+  pub fn new(width: usize, height: usize) -> anyhow::Result<Self> {
+    let stride = width.div_ceil(WORD_BITS);
+    let len = stride.checked_mul(height).ok_or_else(|| {
+      anyhow::anyhow!("Bit grid of {width}x{height} does not fit in memory")
+    })?;
+
+    Ok(Self {
+      width,
+      height,
+      stride,
+      words: vec![0; len],
+    })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  /// Unset outside the grid.
+  pub fn get(&self, position: Point2) -> bool {
+    self
+      .locate(position)
+      .and_then(|(index, mask)| Some(self.words.get(index)? & mask != 0))
+      .unwrap_or_default()
+  }
+
+  /// Previous bit, positions outside the grid are left alone.
+  pub fn set(&mut self, position: Point2, value: bool) -> bool {
+    let Some((index, mask)) = self.locate(position) else {
+      return false;
+    };
+    let Some(word) = self.words.get_mut(index) else {
+      return false;
+    };
+
+    let previous = *word & mask != 0;
+    if value {
+      *word |= mask;
+    } else {
+      *word &= !mask;
+    }
+    previous
+  }
+
+  /// Whether the bit was newly set.
+  pub fn insert(&mut self, position: Point2) -> bool {
+    self.contains(position) && !self.set(position, true)
+  }
+
+  pub fn contains(&self, position: Point2) -> bool {
+    position.x < self.width && position.y < self.height
+  }
+
+  pub fn count(&self) -> usize {
+    self
+      .words
+      .iter()
+      .map(|word| word.count_ones() as usize)
+      .sum()
+  }
+
+  pub fn count_row(&self, y: usize) -> usize {
+    self
+      .row(y)
+      .iter()
+      .map(|word| word.count_ones() as usize)
+      .sum()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.words.iter().all(|&word| word == 0)
+  }
+
+  pub fn clear(&mut self) {
+    self.words.fill(0);
+  }
+
+  /// Set positions row by row.
+  pub fn positions(&self) -> impl Iterator<Item = Point2> + '_ {
+    (0..self.height).flat_map(move |y| {
+      self
+        .row(y)
+        .iter()
+        .enumerate()
+        .flat_map(move |(index, &word)| {
+          let start = index.saturating_mul(WORD_BITS);
+          Bits(word).map(move |bit| Point2::new(start.saturating_add(bit), y))
+        })
+    })
+  }
+
+  /// Sets every bit set in `other` where both grids overlap.
+  pub fn union_with(&mut self, other: &Self) {
+    self.zip_rows(other, |word, other| word | other);
+  }
+
+  /// Keeps only bits also set in `other`, clearing bits `other` lacks.
+  pub fn intersect_with(&mut self, other: &Self) {
+    self.zip_rows(other, |word, other| word & other);
+  }
+
+  /// Clears every bit set in `other`.
+  pub fn difference_with(&mut self, other: &Self) {
+    self.zip_rows(other, |word, other| word & !other);
+  }
+
+  /// Set cells sharing an edge or a corner with `position`.
+  pub fn neighbour_count8(&self, position: Point2) -> usize {
+    Vector2::ADJACENT
+      .into_iter()
+      .filter_map(|offset| position.checked_add(offset))
+      .filter(|&position| self.get(position))
+      .count()
+  }
+
+  /// Cells with at least `count` set cells sharing an edge with them.
+  pub fn neighbours4_at_least(&self, count: usize) -> Self {
+    self.neighbours_at_least(count, |north, row, south| {
+      [
+        north.to_vec(),
+        shift_east(row),
+        shift_west(row),
+        south.to_vec(),
+      ]
+    })
+  }
+
+  /// Cells with at least `count` set cells sharing an edge or a corner with
+  /// them.
+  pub fn neighbours8_at_least(&self, count: usize) -> Self {
+    self.neighbours_at_least(count, |north, row, south| {
+      [
+        shift_east(north),
+        north.to_vec(),
+        shift_west(north),
+        shift_east(row),
+        shift_west(row),
+        shift_east(south),
+        south.to_vec(),
+        shift_west(south),
+      ]
+    })
+  }
+
+  // NOTE: neighbour bits of a whole row get added at once as a 4 bit counter
+  // sliced across 4 words, which is plenty for 8 neighbours
+  fn neighbours_at_least<const N: usize>(
+    &self,
+    count: usize,
+    shifted: impl Fn(&[Word], &[Word], &[Word]) -> [Vec<Word>; N],
+  ) -> Self {
+    let mut result = Self {
+      words: vec![0; self.words.len()],
+      ..self.clone()
+    };
+
+    for y in 0..self.height {
+      let north = y.checked_sub(1).map_or(&[][..], |y| self.row(y));
+      let south = y.checked_add(1).map_or(&[][..], |y| self.row(y));
+      let masks = shifted(north, self.row(y), south);
+
+      for (index, word) in result.row_mut(y).iter_mut().enumerate() {
+        let mut planes = [0 as Word; 4];
+        for mask in masks.iter() {
+          let mut carry = mask.get(index).copied().unwrap_or_default();
+          for plane in planes.iter_mut() {
+            let sum = *plane ^ carry;
+            carry &= *plane;
+            *plane = sum;
+          }
+        }
+        *word = at_least(planes, count);
+      }
+    }
+
+    result.trim();
+    result
+  }
+
+  fn zip_rows(&mut self, other: &Self, f: impl Fn(Word, Word) -> Word) {
+    for y in 0..self.height {
+      let other = other.row(y);
+      for (index, word) in self.row_mut(y).iter_mut().enumerate() {
+        *word = f(*word, other.get(index).copied().unwrap_or_default());
+      }
+    }
+    self.trim();
+  }
+
+  /// Clears bits past the width left over by shifting or negating.
+  fn trim(&mut self) {
+    let tail = self.width.checked_rem(WORD_BITS).unwrap_or_default();
+    if tail == 0 {
+      return;
+    }
+
+    let mask = Word::MAX.checked_shr(WORD_BITS.saturating_sub(tail) as u32);
+    for y in 0..self.height {
+      if let (Some(word), Some(mask)) = (self.row_mut(y).last_mut(), mask) {
+        *word &= mask;
+      }
+    }
+  }
+
+  fn row(&self, y: usize) -> &[Word] {
+    self
+      .row_range(y)
+      .and_then(|range| self.words.get(range))
+      .unwrap_or(&[])
+  }
+
+  fn row_mut(&mut self, y: usize) -> &mut [Word] {
+    match self.row_range(y) {
+      Some(range) => self.words.get_mut(range).unwrap_or(&mut []),
+      None => &mut [],
+    }
+  }
+
+  fn row_range(&self, y: usize) -> Option<std::ops::Range<usize>> {
+    if y >= self.height {
+      return None;
+    }
+
+    let start = y.checked_mul(self.stride)?;
+    Some(start..start.checked_add(self.stride)?)
+  }
+
+  fn locate(&self, position: Point2) -> Option<(usize, Word)> {
+    if !self.contains(position) {
+      return None;
+    }
+
+    let index = position
+      .y
+      .checked_mul(self.stride)?
+      .checked_add(position.x.checked_div(WORD_BITS)?)?;
+    let bit = position.x.checked_rem(WORD_BITS)?;
+    Some((index, (1 as Word).checked_shl(bit as u32)?))
+  }
+}
+
+impl<T> Grid<T> {
+  /// Bits set wherever `predicate` matches.
+  pub fn mask(
+    &self,
+    predicate: impl Fn(&T) -> bool,
+  ) -> anyhow::Result<BitGrid> {
+    let mut mask = BitGrid::new(self.width(), self.height())?;
+    for position in self.find_all(predicate) {
+      mask.set(position, true);
+    }
+    Ok(mask)
+  }
+}
+
+impl Display for BitGrid {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for y in 0..self.height {
+      if y != 0 {
+        writeln!(f)?;
+      }
+      for x in 0..self.width {
+        let char = if self.get(Point2 { x, y }) { '#' } else { '.' };
+        write!(f, "{char}")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Indices of set bits from the lowest.
+struct Bits(Word);
+
+impl Iterator for Bits {
+  type Item = usize;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.0 == 0 {
+      return None;
+    }
+
+    let bit = self.0.trailing_zeros() as usize;
+    self.0 &= self.0.wrapping_sub(1);
+    Some(bit)
+  }
+}
+
+/// Each cell gets the bit of its western neighbour.
+fn shift_east(row: &[Word]) -> Vec<Word> {
+  let mut carry = 0;
+  row
+    .iter()
+    .map(|&word| {
+      let shifted = word.wrapping_shl(1) | carry;
+      carry = word.wrapping_shr(Word::BITS.saturating_sub(1));
+      shifted
+    })
+    .collect()
+}
+
+/// Each cell gets the bit of its eastern neighbour.
+fn shift_west(row: &[Word]) -> Vec<Word> {
+  let mut carry = 0;
+  let mut shifted = row
+    .iter()
+    .rev()
+    .map(|&word| {
+      let shifted = word.wrapping_shr(1) | carry;
+      carry = word.wrapping_shl(Word::BITS.saturating_sub(1));
+      shifted
+    })
+    .collect::<Vec<_>>();
+  shifted.reverse();
+  shifted
+}
+
+/// Bits whose sliced counter across `planes` is at least `count`.
+fn at_least(planes: [Word; 4], count: usize) -> Word {
+  let mut greater = 0;
+  let mut equal = Word::MAX;
+  for (index, plane) in planes.iter().enumerate().rev() {
+    if count.checked_shr(index as u32).unwrap_or_default() & 1 == 1 {
+      equal &= plane;
+    } else {
+      greater |= equal & plane;
+      equal &= !plane;
+    }
+  }
+
+  if count >= 1 << planes.len() {
+    0
+  } else {
+    greater | equal
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn counts_and_combines() -> anyhow::Result<()> {
+    let mut bits = BitGrid::new(70, 2)?;
+    assert!(bits.insert(Point2::new(0, 0)));
+    assert!(!bits.insert(Point2::new(0, 0)));
+    assert!(!bits.insert(Point2::new(70, 0)));
+    bits.set(Point2::new(69, 1), true);
+    assert_eq!((bits.count(), bits.count_row(1)), (2, 1));
+
+    let mut other = BitGrid::new(70, 2)?;
+    other.set(Point2::new(69, 1), true);
+    bits.difference_with(&other);
+    assert_eq!(bits.positions().collect::<Vec<_>>(), [Point2::new(0, 0)]);
+    bits.union_with(&other);
+    assert_eq!(bits.count(), 2);
+    bits.intersect_with(&other);
+    assert_eq!(bits.positions().collect::<Vec<_>>(), [Point2::new(69, 1)]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn counts_shifted_neighbours() -> anyhow::Result<()> {
+    let grid = ["#.##..#", "..#.###.", "####.", ".#..#.#..", "#..##"]
+      .iter()
+      .map(|row| row.chars().cycle().take(130).collect::<String>())
+      .collect::<Vec<_>>()
+      .join("\n")
+      .parse::<Grid<char>>()?;
+    let bits = grid.mask(|&cell| cell == '#')?;
+
+    for count in 0..=9 {
+      let expected = grid
+        .positions()
+        .filter(|&position| bits.neighbour_count8(position) >= count)
+        .collect::<Vec<_>>();
+      let actual = bits
+        .neighbours8_at_least(count)
+        .positions()
+        .collect::<Vec<_>>();
+      assert_eq!(actual, expected);
+
+      let expected = grid
+        .positions()
+        .filter(|&position| {
+          grid.neighbours4(position).filter(|&n| bits.get(n)).count() >= count
+        })
+        .count();
+      assert_eq!(bits.neighbours4_at_least(count).count(), expected);
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,180 @@
+use grind_geometry::{Point2, Vector2};
+
+use crate::{Grid, SparseGrid};
+
+/// Cells looked up by position, so solutions can switch between a dense
+/// [`Grid`] and a [`SparseGrid`] without changing how they walk them.
+pub trait Cells {
+  type Position: Copy;
+  type Cell;
+
+  /// Whether there is a cell at `position`.
+  fn contains(&self, position: Self::Position) -> bool;
+
+  fn get(&self, position: Self::Position) -> Option<&Self::Cell>;
+
+  fn get_mut(&mut self, position: Self::Position) -> Option<&mut Self::Cell>;
+
+  /// Positions on the grid sharing an edge with `position`, clockwise from
+  /// north.
+  fn neighbours4(
+    &self,
+    position: Self::Position,
+  ) -> impl Iterator<Item = Self::Position> + '_;
+
+  /// Positions on the grid sharing an edge or a corner with `position`,
+  /// clockwise from north.
+  fn neighbours8(
+    &self,
+    position: Self::Position,
+  ) -> impl Iterator<Item = Self::Position> + '_;
+
+  /// Positions holding a cell row by row.
+  fn positions(&self) -> impl Iterator<Item = Self::Position> + '_;
+
+  fn iter(&self) -> impl Iterator<Item = (Self::Position, &Self::Cell)> + '_;
+
+  /// First position row by row whose cell matches `predicate`.
+  fn find(
+    &self,
+    predicate: impl Fn(&Self::Cell) -> bool,
+  ) -> Option<Self::Position> {
+    self
+      .iter()
+      .find(|(_, cell)| predicate(cell))
+      .map(|(position, _)| position)
+  }
+
+  fn find_all<'a>(
+    &'a self,
+    predicate: impl Fn(&Self::Cell) -> bool + 'a,
+  ) -> impl Iterator<Item = Self::Position> + 'a {
+    self
+      .iter()
+      .filter(move |(_, cell)| predicate(cell))
+      .map(|(position, _)| position)
+  }
+}
+
+impl<T> Cells for Grid<T> {
+  type Position = Point2;
+  type Cell = T;
+
+  fn contains(&self, position: Point2) -> bool {
+    Grid::contains(self, position)
+  }
+
+  fn get(&self, position: Point2) -> Option<&T> {
+    Grid::get(self, position)
+  }
+
+  fn get_mut(&mut self, position: Point2) -> Option<&mut T> {
+    Grid::get_mut(self, position)
+  }
+
+  fn neighbours4(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+    Grid::neighbours4(self, position)
+  }
+
+  fn neighbours8(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+    Grid::neighbours8(self, position)
+  }
+
+  fn positions(&self) -> impl Iterator<Item = Point2> + '_ {
+    Grid::positions(self)
+  }
+
+  fn iter(&self) -> impl Iterator<Item = (Point2, &T)> + '_ {
+    Grid::iter(self)
+  }
+}
+
+impl<T> Cells for SparseGrid<T> {
+  type Position = Vector2;
+  type Cell = T;
+
+  fn contains(&self, position: Vector2) -> bool {
+    SparseGrid::contains(self, position)
+  }
+
+  fn get(&self, position: Vector2) -> Option<&T> {
+    SparseGrid::get(self, position)
+  }
+
+  fn get_mut(&mut self, position: Vector2) -> Option<&mut T> {
+    SparseGrid::get_mut(self, position)
+  }
+
+  fn neighbours4(
+    &self,
+    position: Vector2,
+  ) -> impl Iterator<Item = Vector2> + '_ {
+    SparseGrid::neighbours4(self, position)
+  }
+
+  fn neighbours8(
+    &self,
+    position: Vector2,
+  ) -> impl Iterator<Item = Vector2> + '_ {
+    SparseGrid::neighbours8(self, position)
+  }
+
+  fn positions(&self) -> impl Iterator<Item = Vector2> + '_ {
+    SparseGrid::positions(self)
+  }
+
+  fn iter(&self) -> impl Iterator<Item = (Vector2, &T)> + '_ {
+    SparseGrid::iter(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashSet, hash::Hash};
+
+  use super::*;
+
+  /// Positions reachable from `start` through neighbours with the same cell.
+  fn region<C: Cells>(cells: &C, start: C::Position) -> usize
+  where
+    C::Position: Eq + Hash,
+    C::Cell: PartialEq,
+  {
+    let mut seen = HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(position) = stack.pop() {
+      let cell = cells.get(position);
+      for neighbour in cells.neighbours4(position) {
+        if cells.get(neighbour) == cell && seen.insert(neighbour) {
+          stack.push(neighbour);
+        }
+      }
+    }
+    seen.len()
+  }
+
+  #[test]
+  fn walks_dense_and_sparse_alike() -> anyhow::Result<()> {
+    let grid = "##.\n.#.\n.##".parse::<Grid<char>>()?;
+    let shift = Vector2::new(-5, -7);
+    let sparse = grid
+      .find_all(|&cell| cell == '#')
+      .map(|position| {
+        let position = Vector2::try_from(position).ok()?.checked_add(shift)?;
+        Some((position, '#'))
+      })
+      .collect::<Option<SparseGrid<_>>>()
+      .ok_or_else(|| anyhow::anyhow!("Cells don't fit a sparse grid"))?;
+
+    let start = grid.find(|&cell| cell == '#');
+    assert_eq!(start.map(|start| region(&grid, start)), Some(5));
+    assert_eq!(Cells::find(&sparse, |&cell| cell == '#'), Some(shift));
+    assert_eq!(region(&sparse, shift), 5);
+    assert_eq!(
+      Cells::positions(&grid).count(),
+      Cells::positions(&sparse).count().saturating_add(4)
+    );
+
+    Ok(())
+  }
+}
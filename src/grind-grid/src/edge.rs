@@ -0,0 +1,99 @@
+use grind_geometry::{Point2, Vector2};
+use grind_math::Modular;
+
+/// What a grid looks like past its edge.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Edge<T> {
+  /// Nothing is there.
+  #[default]
+  Void,
+  /// Every cell outside holds the same tile.
+  Pad(T),
+  /// Leaving one side enters from the opposite one.
+  Wrap,
+  /// Positions outside snap to the nearest cell.
+  Clamp,
+}
+
+impl<T> Edge<T> {
+  /// Cell of a `width` by `height` grid that `position` moved by `offset`
+  /// lands on, none when it lands outside without wrapping or clamping.
+  pub fn resolve(
+    &self,
+    width: usize,
+    height: usize,
+    position: Point2,
+    offset: Vector2,
+  ) -> Option<Point2> {
+    if width == 0 || height == 0 {
+      return None;
+    }
+
+    match self {
+      Edge::Void | Edge::Pad(_) => position
+        .checked_add(offset)
+        .filter(|position| position.x < width && position.y < height),
+      Edge::Wrap => Some(Point2 {
+        x: wrap(position.x, offset.x, width)?,
+        y: wrap(position.y, offset.y, height)?,
+      }),
+      Edge::Clamp => Some(Point2 {
+        x: clamp(position.x, offset.x, width)?,
+        y: clamp(position.y, offset.y, height)?,
+      }),
+    }
+  }
+
+  pub fn pad(&self) -> Option<&T> {
+    match self {
+      Edge::Pad(pad) => Some(pad),
+      _ => None,
+    }
+  }
+
+  /// Same policy for another tile type, padding turns into void.
+  pub fn without_pad<U>(&self) -> Edge<U> {
+    match self {
+      Edge::Void | Edge::Pad(_) => Edge::Void,
+      Edge::Wrap => Edge::Wrap,
+      Edge::Clamp => Edge::Clamp,
+    }
+  }
+}
+
+fn wrap(coordinate: usize, offset: isize, len: usize) -> Option<usize> {
+  let len = isize::try_from(len).ok()?;
+  let coordinate = Modular::new(isize::try_from(coordinate).ok()?, len)?;
+  usize::try_from(coordinate.checked_add(offset)?.value()).ok()
+}
+
+fn clamp(coordinate: usize, offset: isize, len: usize) -> Option<usize> {
+  let last = len.checked_sub(1)?;
+  Some(coordinate.saturating_add_signed(offset).min(last))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn wraps_like_stepping(
+      len in 1usize..20,
+      coordinate in 0usize..20,
+      offset in -100isize..100,
+    ) {
+      let mut stepped = coordinate.checked_rem(len).unwrap_or_default();
+      for _ in 0..offset.unsigned_abs() {
+        stepped = if offset > 0 {
+          stepped.saturating_add(1).checked_rem(len).unwrap_or_default()
+        } else {
+          stepped.checked_sub(1).unwrap_or(len.saturating_sub(1))
+        };
+      }
+
+      prop_assert_eq!(wrap(coordinate, offset, len), Some(stepped));
+    }
+  }
+}
@@ -0,0 +1,336 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::Context;
+
+use grind_geometry::{Point2, Vector2};
+
+use crate::{Edge, Tile};
+
+/// Rectangle of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+  edge: Edge<T>,
+}
+
+impl<T> Grid<T> {
+  pub fn new(width: usize, height: usize, fill: T) -> anyhow::Result<Self>
+  where
+    T: Clone,
+  {
+    let len = width.checked_mul(height).ok_or_else(|| {
+      anyhow::anyhow!("Grid of {width}x{height} does not fit in memory")
+    })?;
+
+    Ok(Self {
+      width,
+      height,
+      cells: vec![fill; len],
+      edge: Edge::Void,
+    })
+  }
+
+  /// Fails unless every row is as wide as the first one.
+  pub fn from_rows(rows: Vec<Vec<T>>) -> anyhow::Result<Self> {
+    let height = rows.len();
+    let width = rows.first().map(Vec::len).unwrap_or_default();
+
+    let mut cells = Vec::with_capacity(width.saturating_mul(height));
+    for (y, row) in rows.into_iter().enumerate() {
+      if row.len() != width {
+        return Err(anyhow::anyhow!(
+          "Row {y} is {} wide instead of {width}",
+          row.len()
+        ));
+      }
+      cells.extend(row);
+    }
+
+    Ok(Self {
+      width,
+      height,
+      cells,
+      edge: Edge::Void,
+    })
+  }
+
+  /// Lookups and neighbours past the edge follow `edge` from now on.
+  pub fn with_edge(self, edge: Edge<T>) -> Self {
+    Self { edge, ..self }
+  }
+
+  pub fn edge(&self) -> &Edge<T> {
+    &self.edge
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  /// Cells row by row.
+  pub fn cells(&self) -> &[T] {
+    &self.cells
+  }
+
+  pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+    self.cells.chunks(self.width.max(1))
+  }
+
+  pub fn contains(&self, position: Point2) -> bool {
+    position.x < self.width && position.y < self.height
+  }
+
+  pub fn get(&self, position: Point2) -> Option<&T> {
+    self.at(position, Vector2::default())
+  }
+
+  /// Only cells on the grid can change, padding stays as it is.
+  pub fn get_mut(&mut self, position: Point2) -> Option<&mut T> {
+    let position = self.offset(position, Vector2::default())?;
+    let index = self.index(position)?;
+    self.cells.get_mut(index)
+  }
+
+  /// Tile at `position` moved by `offset` including padding.
+  pub fn at(&self, position: Point2, offset: Vector2) -> Option<&T> {
+    match self.offset(position, offset) {
+      Some(position) => self.cells.get(self.index(position)?),
+      None => self.edge.pad(),
+    }
+  }
+
+  /// Cell that `position` moved by `offset` lands on following the edge.
+  pub fn offset(&self, position: Point2, offset: Vector2) -> Option<Point2> {
+    self.edge.resolve(self.width, self.height, position, offset)
+  }
+
+  /// Positions sharing an edge with `position`, clockwise from north.
+  pub fn neighbours4(
+    &self,
+    position: Point2,
+  ) -> impl Iterator<Item = Point2> + '_ {
+    Vector2::ORTHOGONAL
+      .into_iter()
+      .filter_map(move |offset| self.offset(position, offset))
+  }
+
+  /// Positions sharing an edge or a corner with `position`, clockwise from
+  /// north.
+  pub fn neighbours8(
+    &self,
+    position: Point2,
+  ) -> impl Iterator<Item = Point2> + '_ {
+    Vector2::ADJACENT
+      .into_iter()
+      .filter_map(move |offset| self.offset(position, offset))
+  }
+
+  /// Tiles sharing an edge with `position` including padding.
+  pub fn neighbour_cells4(
+    &self,
+    position: Point2,
+  ) -> impl Iterator<Item = &T> + '_ {
+    Vector2::ORTHOGONAL
+      .into_iter()
+      .filter_map(move |offset| self.at(position, offset))
+  }
+
+  /// Tiles sharing an edge or a corner with `position` including padding.
+  pub fn neighbour_cells8(
+    &self,
+    position: Point2,
+  ) -> impl Iterator<Item = &T> + '_ {
+    Vector2::ADJACENT
+      .into_iter()
+      .filter_map(move |offset| self.at(position, offset))
+  }
+
+  /// Every position row by row.
+  pub fn positions(&self) -> impl Iterator<Item = Point2> {
+    let width = self.width;
+    (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2 { x, y }))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+    self.positions().zip(self.cells.iter())
+  }
+
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point2, &mut T)> {
+    self.positions().zip(self.cells.iter_mut())
+  }
+
+  /// First position row by row whose cell matches `predicate`.
+  pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point2> {
+    self
+      .iter()
+      .find(|(_, cell)| predicate(cell))
+      .map(|(position, _)| position)
+  }
+
+  pub fn find_all<'a>(
+    &'a self,
+    predicate: impl Fn(&T) -> bool + 'a,
+  ) -> impl Iterator<Item = Point2> + 'a {
+    self
+      .iter()
+      .filter(move |(_, cell)| predicate(cell))
+      .map(|(position, _)| position)
+  }
+
+  /// Keeps the edge except for padding.
+  pub fn map<U>(&self, mut f: impl FnMut(Point2, &T) -> U) -> Grid<U> {
+    Grid {
+      width: self.width,
+      height: self.height,
+      cells: self
+        .iter()
+        .map(|(position, cell)| f(position, cell))
+        .collect(),
+      edge: self.edge.without_pad(),
+    }
+  }
+
+  pub fn try_map<U>(
+    &self,
+    mut f: impl FnMut(Point2, &T) -> anyhow::Result<U>,
+  ) -> anyhow::Result<Grid<U>> {
+    Ok(Grid {
+      width: self.width,
+      height: self.height,
+      cells: self
+        .iter()
+        .map(|(position, cell)| f(position, cell))
+        .collect::<anyhow::Result<_>>()?,
+      edge: self.edge.without_pad(),
+    })
+  }
+
+  fn index(&self, position: Point2) -> Option<usize> {
+    if !self.contains(position) {
+      return None;
+    }
+
+    position.y.checked_mul(self.width)?.checked_add(position.x)
+  }
+}
+
+/// Reads one row per non-empty line ignoring surrounding whitespace.
+impl<T: Tile> FromStr for Grid<T> {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let rows = s
+      .lines()
+      .map(str::trim)
+      .filter(|line| !line.is_empty())
+      .enumerate()
+      .map(|(y, line)| {
+        line
+          .chars()
+          .enumerate()
+          .map(|(x, char)| {
+            T::from_char(char).with_context(|| {
+              format!("Invalid tile {char:?} at {}", Point2 { x, y })
+            })
+          })
+          .collect::<anyhow::Result<Vec<_>>>()
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Self::from_rows(rows)
+  }
+}
+
+impl<T: Tile> Display for Grid<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (y, row) in self.rows().enumerate() {
+      if y != 0 {
+        writeln!(f)?;
+      }
+      for cell in row {
+        write!(f, "{}", cell.to_char())?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_and_displays() -> anyhow::Result<()> {
+    let text = "#.#\n.S.\n";
+    let grid = text.parse::<Grid<char>>()?;
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.find(|&cell| cell == 'S'), Some(Point2::new(1, 1)));
+    assert_eq!(grid.to_string(), text.trim());
+    assert!("##\n#".parse::<Grid<char>>().is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn checks_bounds() -> anyhow::Result<()> {
+    let mut grid = Grid::new(2, 2, 0u8)?;
+
+    assert_eq!(grid.get(Point2::new(2, 0)), None);
+    assert_eq!(grid.offset(Point2::new(0, 0), Vector2::WEST), None);
+    if let Some(cell) = grid.get_mut(Point2::new(1, 1)) {
+      *cell = 1;
+    }
+    assert_eq!(grid.find_all(|&cell| cell == 1).count(), 1);
+
+    Ok(())
+  }
+
+  #[test]
+  fn iterates_neighbours() -> anyhow::Result<()> {
+    let grid = Grid::new(3, 3, ())?;
+    let corner = Point2::new(0, 0);
+    let center = Point2::new(1, 1);
+
+    assert_eq!(grid.neighbours4(corner).count(), 2);
+    assert_eq!(grid.neighbours8(corner).count(), 3);
+    assert_eq!(grid.neighbours4(center).count(), 4);
+    assert_eq!(grid.neighbours8(center).count(), 8);
+
+    Ok(())
+  }
+
+  #[test]
+  fn follows_edge() -> anyhow::Result<()> {
+    let grid = "ab\ncd".parse::<Grid<char>>()?;
+    let corner = Point2::new(0, 0);
+    let outside =
+      |grid: &Grid<char>| grid.at(corner, Vector2::NORTH_WEST).copied();
+
+    assert_eq!(outside(&grid), None);
+    let grid = grid.with_edge(Edge::Pad('.'));
+    assert_eq!(outside(&grid), Some('.'));
+    assert_eq!(
+      grid.neighbour_cells8(corner).filter(|&&c| c == '.').count(),
+      5
+    );
+    let grid = grid.with_edge(Edge::Wrap);
+    assert_eq!(outside(&grid), Some('d'));
+    assert_eq!(grid.get(Point2::new(2, 3)), Some(&'c'));
+    let grid = grid.with_edge(Edge::Clamp);
+    assert_eq!(outside(&grid), Some('a'));
+    assert_eq!(
+      grid.offset(corner, Vector2::new(5, 5)),
+      Some(Point2::new(1, 1))
+    );
+
+    Ok(())
+  }
+}
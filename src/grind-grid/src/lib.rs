@@ -0,0 +1,26 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+extern crate self as grind_grid;
+
+pub mod bits;
+pub mod cells;
+pub mod edge;
+pub mod grid;
+pub mod sparse;
+pub mod tile;
+
+pub use bits::BitGrid;
+pub use cells::Cells;
+pub use edge::Edge;
+pub use grid::Grid;
+pub use grind_grid_derive::Tile;
+pub use sparse::SparseGrid;
+pub use tile::{Tile, UnknownTile};
+
+#[doc(hidden)]
+pub use anyhow as __anyhow;
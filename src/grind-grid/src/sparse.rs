@@ -0,0 +1,314 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use grind_geometry::Vector2;
+
+use crate::Tile;
+
+/// Drawn for positions in the window that hold no cell.
+pub const BLANK: char = '.';
+
+/// Cells that only exist once set on a grid without bounds, for coordinates
+/// too spread out for a dense grid or reaching into negative ones.
+///
+/// Positions are `Vector2`s from the origin so they can go either way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparseGrid<T> {
+  cells: BTreeMap<Key, T>,
+  window: Option<(Vector2, Vector2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T> SparseGrid<T> {
+  pub fn new() -> Self {
+    Self {
+      cells: BTreeMap::new(),
+      window: None,
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.cells.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.cells.is_empty()
+  }
+
+  /// Top left and bottom right corner of the set positions.
+  pub fn window(&self) -> Option<(Vector2, Vector2)> {
+    self.window
+  }
+
+  pub fn width(&self) -> usize {
+    self
+      .window
+      .map(|(min, max)| max.x.abs_diff(min.x).saturating_add(1))
+      .unwrap_or_default()
+  }
+
+  pub fn height(&self) -> usize {
+    self
+      .window
+      .map(|(min, max)| max.y.abs_diff(min.y).saturating_add(1))
+      .unwrap_or_default()
+  }
+
+  /// Grows the window to fit `position`.
+  pub fn insert(&mut self, position: Vector2, tile: T) -> Option<T> {
+    self.grow(position);
+    self.cells.insert(Key::from(position), tile)
+  }
+
+  /// Shrinks the window when `position` was on its edge.
+  pub fn remove(&mut self, position: Vector2) -> Option<T> {
+    let removed = self.cells.remove(&Key::from(position))?;
+    if self.window.is_some_and(|(min, max)| {
+      position.x == min.x
+        || position.y == min.y
+        || position.x == max.x
+        || position.y == max.y
+    }) {
+      self.window = self
+        .cells
+        .keys()
+        .fold(None, |window, &key| Some(grown(window, Vector2::from(key))));
+    }
+    Some(removed)
+  }
+
+  pub fn get_or_insert_with(
+    &mut self,
+    position: Vector2,
+    tile: impl FnOnce() -> T,
+  ) -> &mut T {
+    self.grow(position);
+    self.cells.entry(Key::from(position)).or_insert_with(tile)
+  }
+
+  /// Whether a cell was set at `position`.
+  pub fn contains(&self, position: Vector2) -> bool {
+    self.cells.contains_key(&Key::from(position))
+  }
+
+  pub fn get(&self, position: Vector2) -> Option<&T> {
+    self.cells.get(&Key::from(position))
+  }
+
+  pub fn get_mut(&mut self, position: Vector2) -> Option<&mut T> {
+    self.cells.get_mut(&Key::from(position))
+  }
+
+  /// Positions sharing an edge with `position`, clockwise from north, which
+  /// is all four of them unless the coordinates overflow.
+  pub fn neighbours4(
+    &self,
+    position: Vector2,
+  ) -> impl Iterator<Item = Vector2> + '_ {
+    neighbours(position, Vector2::ORTHOGONAL)
+  }
+
+  /// Positions sharing an edge or a corner with `position`, clockwise from
+  /// north.
+  pub fn neighbours8(
+    &self,
+    position: Vector2,
+  ) -> impl Iterator<Item = Vector2> + '_ {
+    neighbours(position, Vector2::ADJACENT)
+  }
+
+  /// Set cells sharing an edge with `position`.
+  pub fn neighbour_cells4(
+    &self,
+    position: Vector2,
+  ) -> impl Iterator<Item = &T> + '_ {
+    self
+      .neighbours4(position)
+      .filter_map(move |position| self.get(position))
+  }
+
+  /// Set cells sharing an edge or a corner with `position`.
+  pub fn neighbour_cells8(
+    &self,
+    position: Vector2,
+  ) -> impl Iterator<Item = &T> + '_ {
+    self
+      .neighbours8(position)
+      .filter_map(move |position| self.get(position))
+  }
+
+  /// Set positions row by row.
+  pub fn positions(&self) -> impl Iterator<Item = Vector2> + '_ {
+    self.cells.keys().map(|&key| Vector2::from(key))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (Vector2, &T)> {
+    self
+      .cells
+      .iter()
+      .map(|(&key, cell)| (Vector2::from(key), cell))
+  }
+
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vector2, &mut T)> {
+    self
+      .cells
+      .iter_mut()
+      .map(|(&key, cell)| (Vector2::from(key), cell))
+  }
+
+  /// First set position row by row whose cell matches `predicate`.
+  pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Vector2> {
+    self
+      .iter()
+      .find(|(_, cell)| predicate(cell))
+      .map(|(position, _)| position)
+  }
+
+  pub fn find_all<'a>(
+    &'a self,
+    predicate: impl Fn(&T) -> bool + 'a,
+  ) -> impl Iterator<Item = Vector2> + 'a {
+    self
+      .iter()
+      .filter(move |(_, cell)| predicate(cell))
+      .map(|(position, _)| position)
+  }
+
+  /// Keeps the window.
+  pub fn map<U>(&self, mut f: impl FnMut(Vector2, &T) -> U) -> SparseGrid<U> {
+    SparseGrid {
+      cells: self
+        .cells
+        .iter()
+        .map(|(&key, cell)| (key, f(Vector2::from(key), cell)))
+        .collect(),
+      window: self.window,
+    }
+  }
+
+  fn grow(&mut self, position: Vector2) {
+    self.window = Some(grown(self.window, position));
+  }
+}
+
+fn neighbours<const N: usize>(
+  position: Vector2,
+  offsets: [Vector2; N],
+) -> impl Iterator<Item = Vector2> {
+  offsets
+    .into_iter()
+    .filter_map(move |offset| position.checked_add(offset))
+}
+
+fn grown(
+  window: Option<(Vector2, Vector2)>,
+  position: Vector2,
+) -> (Vector2, Vector2) {
+  match window {
+    Some((min, max)) => (
+      Vector2::new(min.x.min(position.x), min.y.min(position.y)),
+      Vector2::new(max.x.max(position.x), max.y.max(position.y)),
+    ),
+    None => (position, position),
+  }
+}
+
+impl<T> FromIterator<(Vector2, T)> for SparseGrid<T> {
+  fn from_iter<I: IntoIterator<Item = (Vector2, T)>>(iter: I) -> Self {
+    let mut grid = Self::new();
+    for (position, tile) in iter {
+      grid.insert(position, tile);
+    }
+    grid
+  }
+}
+
+/// Draws the window row by row with `BLANK` where no cell is set.
+impl<T: Tile> Display for SparseGrid<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Some((min, max)) = self.window else {
+      return Ok(());
+    };
+
+    for y in min.y..=max.y {
+      if y != min.y {
+        writeln!(f)?;
+      }
+      for x in min.x..=max.x {
+        let char = self.get(Vector2 { x, y }).map_or(BLANK, Tile::to_char);
+        write!(f, "{char}")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Orders positions row by row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Key {
+  y: isize,
+  x: isize,
+}
+
+impl From<Vector2> for Key {
+  fn from(position: Vector2) -> Self {
+    Self {
+      y: position.y,
+      x: position.x,
+    }
+  }
+}
+
+impl From<Key> for Vector2 {
+  fn from(key: Key) -> Self {
+    Self { x: key.x, y: key.y }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn renders_window() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.to_string(), "");
+
+    grid.insert(Vector2::new(40_000, -20_000), '#');
+    grid.insert(Vector2::new(40_002, -19_999), '#');
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.to_string(), "#..\n..#");
+
+    grid.insert(Vector2::new(40_001, -20_000), '#');
+    grid.remove(Vector2::new(40_002, -19_999));
+    assert_eq!(grid.len(), 2);
+    assert_eq!((grid.width(), grid.height()), (2, 1));
+    assert_eq!(grid.to_string(), "##");
+
+    grid.remove(Vector2::new(40_001, -20_000));
+    grid.remove(Vector2::new(40_000, -20_000));
+    assert_eq!(grid.window(), None);
+  }
+
+  #[test]
+  fn iterates_row_by_row() {
+    let grid = [(0, 0), (-1, 0), (0, -1)]
+      .into_iter()
+      .map(|(x, y)| (Vector2::new(x, y), ()))
+      .collect::<SparseGrid<_>>();
+
+    assert_eq!(
+      grid.positions().collect::<Vec<_>>(),
+      [Vector2::new(0, -1), Vector2::new(-1, 0), Vector2::new(0, 0)]
+    );
+    assert_eq!(grid.neighbours4(Vector2::new(-1, -1)).count(), 4);
+    assert_eq!(grid.neighbour_cells4(Vector2::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbour_cells8(Vector2::new(-1, -1)).count(), 3);
+    assert_eq!(grid.neighbours8(Vector2::new(isize::MAX, 0)).count(), 5);
+  }
+}
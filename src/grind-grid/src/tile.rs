@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+/// Cell that is written as a single character in puzzle inputs.
+pub trait Tile: Sized {
+  fn from_char(char: char) -> anyhow::Result<Self>;
+
+  fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+  fn from_char(char: char) -> anyhow::Result<Self> {
+    Ok(char)
+  }
+
+  fn to_char(&self) -> char {
+    *self
+  }
+}
+
+/// Text that does not stand for any tile of a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTile {
+  tile: &'static str,
+  text: String,
+  legend: &'static [(char, &'static str)],
+}
+
+impl UnknownTile {
+  /// `legend` pairs every known character with the tile it stands for.
+  pub fn new(
+    tile: &'static str,
+    text: impl ToString,
+    legend: &'static [(char, &'static str)],
+  ) -> Self {
+    Self {
+      tile,
+      text: text.to_string(),
+      legend,
+    }
+  }
+
+  /// Name of the tile type.
+  pub fn tile(&self) -> &'static str {
+    self.tile
+  }
+
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+
+  /// The offending character when the text was a single one.
+  pub fn char(&self) -> Option<char> {
+    let mut chars = self.text.chars();
+    match (chars.next(), chars.next()) {
+      (Some(char), None) => Some(char),
+      _ => None,
+    }
+  }
+}
+
+impl Display for UnknownTile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Unknown {} {:?}, expected one of ", self.tile, self.text)?;
+    for (index, (char, name)) in self.legend.iter().enumerate() {
+      if index != 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{char:?} for {name}")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl std::error::Error for UnknownTile {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Tile;
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+  enum Cave {
+    #[tile('#')]
+    Rock,
+    #[tile('.')]
+    OpenAir,
+  }
+
+  #[test]
+  fn derives_tiles() {
+    assert_eq!(Cave::from_char('#').ok(), Some(Cave::Rock));
+    assert_eq!(".".parse::<Cave>(), Ok(Cave::OpenAir));
+    assert_eq!(Cave::OpenAir.to_char(), Cave::OPEN_AIR_CHAR);
+    assert_eq!(
+      format!("{:>3}", Cave::Rock),
+      format!("  {}", Cave::ROCK_STR)
+    );
+    assert_eq!(Cave::LEGEND, [('#', "Rock"), ('.', "OpenAir")]);
+
+    let unknown = Cave::try_from('~').err();
+    assert_eq!(unknown.as_ref().and_then(UnknownTile::char), Some('~'));
+    assert_eq!(
+      unknown.map(|unknown| unknown.to_string()).as_deref(),
+      Some("Unknown Cave \"~\", expected one of '#' for Rock, '.' for OpenAir")
+    );
+    assert_eq!(
+      "##".parse::<Cave>().map_err(|unknown| unknown.char()),
+      Err(None)
+    );
+  }
+}
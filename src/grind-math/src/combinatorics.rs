@@ -0,0 +1,219 @@
+/// Every way to pick one of `choices` for each of `len` positions, counting
+/// up like a base `choices` number with the last position turning fastest.
+pub fn assignments(choices: usize, len: usize) -> Assignments {
+  Assignments {
+    choices,
+    next: (choices != 0 || len == 0).then(|| vec![0; len]),
+  }
+}
+
+/// Masks of the lowest `of` bits with exactly `size` of them set, from the
+/// smallest.
+pub fn subsets(size: u32, of: u32) -> Subsets {
+  let first = if size == 0 {
+    Some(0)
+  } else {
+    u128::MAX.checked_shr(u128::BITS.saturating_sub(size))
+  };
+
+  Subsets {
+    next: first.filter(|_| size <= of && of <= u128::BITS),
+    of,
+  }
+}
+
+/// Orderings of the indices below `len` from the sorted one onwards.
+pub fn permutations(len: usize) -> Permutations {
+  Permutations {
+    next: Some((0..len).collect()),
+  }
+}
+
+/// Sorted picks of `size` indices below `len` from the smallest.
+pub fn combinations(len: usize, size: usize) -> Combinations {
+  Combinations {
+    len,
+    next: (size <= len).then(|| (0..size).collect()),
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Assignments {
+  choices: usize,
+  next: Option<Vec<usize>>,
+}
+
+impl Iterator for Assignments {
+  type Item = Vec<usize>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.next.take()?;
+
+    let mut next = current.clone();
+    for choice in next.iter_mut().rev() {
+      match choice
+        .checked_add(1)
+        .filter(|choice| *choice < self.choices)
+      {
+        Some(turned) => {
+          *choice = turned;
+          self.next = Some(next);
+          break;
+        }
+        None => *choice = 0,
+      }
+    }
+
+    Some(current)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Subsets {
+  next: Option<u128>,
+  of: u32,
+}
+
+impl Iterator for Subsets {
+  type Item = u128;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.next.take()?;
+
+    // NOTE: Gosper's hack moves the lowest block of ones up by one and packs
+    // the rest of it back down to the bottom
+    let lowest = current & current.wrapping_neg();
+    self.next = current
+      .checked_add(lowest)
+      .and_then(|ripple| {
+        let ones = ((ripple ^ current) >> 2).checked_div(lowest)?;
+        Some(ripple | ones)
+      })
+      .filter(|next| {
+        next.checked_shr(self.of).is_none_or(|outside| outside == 0)
+      });
+
+    Some(current)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Permutations {
+  next: Option<Vec<usize>>,
+}
+
+impl Iterator for Permutations {
+  type Item = Vec<usize>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.next.take()?;
+
+    let mut next = current.clone();
+    let pivot = next
+      .windows(2)
+      .rposition(|pair| matches!(pair, [lhs, rhs] if lhs < rhs));
+    if let Some(pivot) = pivot {
+      let value = next.get(pivot).copied();
+      if let Some(swapped) = next.iter().rposition(|other| Some(*other) > value)
+      {
+        next.swap(pivot, swapped);
+      }
+      if let Some(rest) =
+        pivot.checked_add(1).and_then(|rest| next.get_mut(rest..))
+      {
+        rest.reverse();
+      }
+      self.next = Some(next);
+    }
+
+    Some(current)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Combinations {
+  len: usize,
+  next: Option<Vec<usize>>,
+}
+
+impl Iterator for Combinations {
+  type Item = Vec<usize>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.next.take()?;
+
+    let mut next = current.clone();
+    let room = self.len.saturating_sub(next.len());
+    let turned = next
+      .iter()
+      .enumerate()
+      .rposition(|(position, index)| *index < position.saturating_add(room));
+    if let Some(turned) = turned {
+      if let Some(rest) = next.get_mut(turned..) {
+        let start = rest.first().map_or(0, |index| index.saturating_add(1));
+        for (index, value) in rest.iter_mut().zip(start..) {
+          *index = value;
+        }
+      }
+      self.next = Some(next);
+    }
+
+    Some(current)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn counts_up_assignments_and_subsets() {
+    assert_eq!(
+      assignments(3, 2).collect::<Vec<_>>(),
+      [
+        [0, 0],
+        [0, 1],
+        [0, 2],
+        [1, 0],
+        [1, 1],
+        [1, 2],
+        [2, 0],
+        [2, 1],
+        [2, 2]
+      ]
+    );
+    assert_eq!(assignments(0, 0).count(), 1);
+    assert_eq!(assignments(0, 1).count(), 0);
+    assert_eq!(
+      subsets(2, 4).collect::<Vec<_>>(),
+      [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+    );
+    assert_eq!(subsets(0, 3).collect::<Vec<_>>(), [0]);
+    assert_eq!(subsets(128, 128).collect::<Vec<_>>(), [u128::MAX]);
+    assert_eq!(subsets(127, 128).count(), 128);
+    assert_eq!(subsets(4, 3).count(), 0);
+  }
+
+  #[test]
+  fn orders_permutations_and_combinations() {
+    assert_eq!(
+      permutations(3).collect::<Vec<_>>(),
+      [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0]
+      ]
+    );
+    assert_eq!(permutations(0).count(), 1);
+    assert_eq!(
+      combinations(4, 2).collect::<Vec<_>>(),
+      [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]
+    );
+    assert_eq!(combinations(3, 0).count(), 1);
+    assert_eq!(combinations(2, 3).count(), 0);
+    assert_eq!(combinations(30, 3).nth(4059), Some(vec![27, 28, 29]));
+  }
+}
@@ -0,0 +1,94 @@
+use std::{
+  fmt::{Debug, Display},
+  hash::Hash,
+};
+
+/// Primitive integer of any width and sign.
+pub trait Integer:
+  Copy + Ord + Hash + Default + Debug + Display + Send + Sync + 'static
+{
+  const ZERO: Self;
+  const ONE: Self;
+  const TWO: Self;
+  const TEN: Self;
+  const MIN: Self;
+  const MAX: Self;
+
+  fn checked_add(self, other: Self) -> Option<Self>;
+  fn checked_sub(self, other: Self) -> Option<Self>;
+  fn checked_mul(self, other: Self) -> Option<Self>;
+  fn checked_div(self, other: Self) -> Option<Self>;
+  fn checked_rem(self, other: Self) -> Option<Self>;
+  fn checked_neg(self) -> Option<Self>;
+  fn checked_pow(self, exp: u32) -> Option<Self>;
+  fn saturating_add(self, other: Self) -> Self;
+  fn saturating_sub(self, other: Self) -> Self;
+  fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! integer {
+  ($($type:ty),+) => {
+    $(
+      impl Integer for $type {
+        const ZERO: Self = 0;
+        const ONE: Self = 1;
+        const TWO: Self = 2;
+        const TEN: Self = 10;
+        const MIN: Self = <$type>::MIN;
+        const MAX: Self = <$type>::MAX;
+
+        fn checked_add(self, other: Self) -> Option<Self> {
+          <$type>::checked_add(self, other)
+        }
+
+        fn checked_sub(self, other: Self) -> Option<Self> {
+          <$type>::checked_sub(self, other)
+        }
+
+        fn checked_mul(self, other: Self) -> Option<Self> {
+          <$type>::checked_mul(self, other)
+        }
+
+        fn checked_div(self, other: Self) -> Option<Self> {
+          <$type>::checked_div(self, other)
+        }
+
+        fn checked_rem(self, other: Self) -> Option<Self> {
+          <$type>::checked_rem(self, other)
+        }
+
+        fn checked_neg(self) -> Option<Self> {
+          <$type>::checked_neg(self)
+        }
+
+        fn checked_pow(self, exp: u32) -> Option<Self> {
+          <$type>::checked_pow(self, exp)
+        }
+
+        fn saturating_add(self, other: Self) -> Self {
+          <$type>::saturating_add(self, other)
+        }
+
+        fn saturating_sub(self, other: Self) -> Self {
+          <$type>::saturating_sub(self, other)
+        }
+
+        fn saturating_mul(self, other: Self) -> Self {
+          <$type>::saturating_mul(self, other)
+        }
+      }
+    )+
+  };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Integer that can go below zero.
+pub trait Signed: Integer {}
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
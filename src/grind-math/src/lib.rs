@@ -0,0 +1,28 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+pub mod combinatorics;
+pub mod integer;
+pub mod linear;
+pub mod modular;
+pub mod number;
+pub mod range_set;
+pub mod rational;
+
+pub use combinatorics::{
+  assignments, combinations, permutations, subsets, Assignments, Combinations,
+  Permutations, Subsets,
+};
+pub use integer::{Integer, Signed};
+pub use linear::{solve, solve_gf2, Gf2SolutionSpace, SolutionSpace};
+pub use modular::Modular;
+pub use number::{
+  chinese_remainder, concat_digits, digit_count, extended_gcd, gcd, isqrt, lcm,
+  mod_inverse, mul_mod, pow10, split_digits,
+};
+pub use range_set::RangeSet;
+pub use rational::Rational;
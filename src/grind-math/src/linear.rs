@@ -0,0 +1,571 @@
+use std::ops::RangeInclusive;
+
+use crate::{extended_gcd, lcm, Rational};
+
+/// Every solution of a linear system as one particular solution plus any
+/// combination of the null space.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SolutionSpace {
+  particular: Vec<Rational>,
+  null_space: Vec<Vec<Rational>>,
+  free: Vec<usize>,
+}
+
+/// Solves `coefficients * x = constants` with Gauss-Jordan elimination.
+///
+/// Nothing when the system has no solution, the numbers overflow or there
+/// isn't one constant per row and as many coefficients in every row.
+pub fn solve(
+  coefficients: &[Vec<Rational>],
+  constants: &[Rational],
+) -> Option<SolutionSpace> {
+  let variables = coefficients.first().map_or(0, Vec::len);
+  if constants.len() != coefficients.len()
+    || coefficients.iter().any(|row| row.len() != variables)
+  {
+    return None;
+  }
+
+  let mut rows = coefficients
+    .iter()
+    .zip(constants.iter())
+    .map(|(row, &constant)| {
+      let mut row = row.clone();
+      row.push(constant);
+      row
+    })
+    .collect::<Vec<_>>();
+
+  let mut pivots = Vec::new();
+  for column in 0..variables {
+    let rank = pivots.len();
+    let Some(found) = (rank..rows.len()).find(|&index| {
+      rows
+        .get(index)
+        .and_then(|row| row.get(column))
+        .is_some_and(|value| !value.is_zero())
+    }) else {
+      continue;
+    };
+    rows.swap(rank, found);
+
+    let pivot_row = rows.get(rank)?;
+    let pivot = *pivot_row.get(column)?;
+    let pivot_row = pivot_row
+      .iter()
+      .map(|value| value.checked_div(pivot))
+      .collect::<Option<Vec<_>>>()?;
+    for (index, row) in rows.iter_mut().enumerate() {
+      if index == rank {
+        row.clone_from(&pivot_row);
+        continue;
+      }
+      let factor = *row.get(column)?;
+      if factor.is_zero() {
+        continue;
+      }
+      for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()) {
+        *value = value.checked_sub(factor.checked_mul(*pivot_value)?)?;
+      }
+    }
+    pivots.push(column);
+  }
+
+  if rows
+    .iter()
+    .skip(pivots.len())
+    .any(|row| row.last().is_some_and(|constant| !constant.is_zero()))
+  {
+    return None;
+  }
+
+  let mut particular = vec![Rational::ZERO; variables];
+  for (row, &column) in rows.iter().zip(pivots.iter()) {
+    *particular.get_mut(column)? = row.last().copied().unwrap_or_default();
+  }
+
+  let free = (0..variables)
+    .filter(|column| !pivots.contains(column))
+    .collect::<Vec<_>>();
+  let null_space = free
+    .iter()
+    .map(|&free_column| {
+      let mut vector = vec![Rational::ZERO; variables];
+      *vector.get_mut(free_column)? = Rational::ONE;
+      for (row, &column) in rows.iter().zip(pivots.iter()) {
+        *vector.get_mut(column)? = row.get(free_column)?.checked_neg()?;
+      }
+      Some(vector)
+    })
+    .collect::<Option<Vec<_>>>()?;
+
+  Some(SolutionSpace {
+    particular,
+    null_space,
+    free,
+  })
+}
+
+impl SolutionSpace {
+  pub fn particular(&self) -> &[Rational] {
+    &self.particular
+  }
+
+  pub fn null_space(&self) -> &[Vec<Rational>] {
+    &self.null_space
+  }
+
+  /// Variables the null space is spanned by, one per null space vector.
+  pub fn free(&self) -> &[usize] {
+    &self.free
+  }
+
+  /// The only solution if there is exactly one.
+  pub fn unique(&self) -> Option<&[Rational]> {
+    self
+      .null_space
+      .is_empty()
+      .then_some(self.particular.as_slice())
+  }
+
+  /// Solution with the free variables set to `values`.
+  pub fn at(&self, values: &[Rational]) -> Option<Vec<Rational>> {
+    let mut solution = self.particular.clone();
+    for (vector, value) in self.null_space.iter().zip(values.iter()) {
+      for (variable, offset) in solution.iter_mut().zip(vector.iter()) {
+        *variable = variable.checked_add(offset.checked_mul(*value)?)?;
+      }
+    }
+    Some(solution)
+  }
+
+  /// Integer solutions with every variable inside its bound, found by trying
+  /// every value of the free variables within theirs.
+  ///
+  /// That is as many candidates as [`SolutionSpace::candidates`] counts, the
+  /// product of the sizes of the free variables' bounds, so callers should
+  /// check it before running through all of them. Variables past the end of
+  /// `bounds` can't take any value.
+  pub fn integer_solutions<'a>(
+    &'a self,
+    bounds: &'a [RangeInclusive<i128>],
+  ) -> impl Iterator<Item = Vec<i128>> + 'a {
+    let ranges = self
+      .free
+      .iter()
+      .map(|&variable| bounds.get(variable).cloned())
+      .collect::<Option<Vec<_>>>();
+
+    // NOTE: scaling everything to a common denominator up front leaves only
+    // integer arithmetic for each of the many candidates
+    ranges.zip(Scaled::new(self)).into_iter().flat_map(
+      move |(ranges, scaled)| {
+        Odometer::new(ranges)
+          .filter_map(move |values| scaled.solution(&values, bounds))
+      },
+    )
+  }
+
+  /// Candidates [`SolutionSpace::integer_solutions`] tries for `bounds`,
+  /// nothing when there are more than fit a `u128`.
+  pub fn candidates(&self, bounds: &[RangeInclusive<i128>]) -> Option<u128> {
+    self.free.iter().try_fold(1u128, |candidates, &variable| {
+      let Some(bound) = bounds.get(variable) else {
+        return Some(0);
+      };
+      let size = if bound.is_empty() {
+        0
+      } else {
+        bound
+          .end()
+          .checked_sub(*bound.start())?
+          .checked_add(1)
+          .and_then(|size| u128::try_from(size).ok())?
+      };
+      candidates.checked_mul(size)
+    })
+  }
+
+  /// Whether any solution is all integers, however large.
+  ///
+  /// Nothing when the numbers overflow on the way.
+  pub fn has_integer_solution(&self) -> Option<bool> {
+    // NOTE: free variables are variables too so they only take integers,
+    // which leaves every scaled variable to be some multiple of the
+    // denominator with one more unknown per variable for that multiple
+    let scaled = Scaled::new(self)?;
+    let free = scaled.null_space.len();
+    let columns = free.checked_add(scaled.particular.len())?;
+    let rows = (0..scaled.particular.len())
+      .map(|variable| {
+        let mut row = scaled
+          .null_space
+          .iter()
+          .map(|vector| vector.get(variable).copied())
+          .collect::<Option<Vec<_>>>()?;
+        row.resize(columns, 0);
+        *row.get_mut(free.checked_add(variable)?)? =
+          scaled.denominator.checked_neg()?;
+        Some(row)
+      })
+      .collect::<Option<Vec<_>>>()?;
+    let constants = scaled
+      .particular
+      .iter()
+      .map(|value| value.checked_neg())
+      .collect::<Option<Vec<_>>>()?;
+
+    has_integer_solution(rows, &constants)
+  }
+}
+
+/// Whether `rows * x = constants` has an integer solution.
+///
+/// Column operations that keep the determinant at one bring the rows into
+/// echelon form without changing which constants are reachable, after which
+/// the variables are solved for one after the other.
+fn has_integer_solution(
+  mut rows: Vec<Vec<i128>>,
+  constants: &[i128],
+) -> Option<bool> {
+  let columns = rows.first().map_or(0, Vec::len);
+  let mut solution = Vec::<i128>::new();
+  for (index, &constant) in constants.iter().enumerate() {
+    let pivot = solution.len();
+    for other in pivot.saturating_add(1)..columns {
+      let row = rows.get(index)?;
+      let (lhs, rhs) = (*row.get(pivot)?, *row.get(other)?);
+      if rhs == 0 {
+        continue;
+      }
+
+      // NOTE: lhs * x + rhs * y is the divisor so the pivot column becomes
+      // the divisor and the other one zero in this row
+      let (divisor, x, y) = extended_gcd(lhs, rhs)?;
+      let (lhs, rhs) = (lhs.checked_div(divisor)?, rhs.checked_div(divisor)?);
+      for row in rows.iter_mut() {
+        let (pivot_value, other_value) = (*row.get(pivot)?, *row.get(other)?);
+        *row.get_mut(pivot)? = pivot_value
+          .checked_mul(x)?
+          .checked_add(other_value.checked_mul(y)?)?;
+        *row.get_mut(other)? = other_value
+          .checked_mul(lhs)?
+          .checked_sub(pivot_value.checked_mul(rhs)?)?;
+      }
+    }
+
+    let row = rows.get(index)?;
+    let rest = row.iter().zip(solution.iter()).try_fold(
+      constant,
+      |rest, (coefficient, value)| {
+        rest.checked_sub(coefficient.checked_mul(*value)?)
+      },
+    )?;
+    match row.get(pivot).copied().filter(|&pivot| pivot != 0) {
+      Some(pivot) if rest.checked_rem(pivot)? == 0 => {
+        solution.push(rest.checked_div(pivot)?);
+      }
+      None if rest == 0 => {}
+      _ => return Some(false),
+    }
+  }
+
+  Some(true)
+}
+
+/// Solution space multiplied by a common denominator of all its values.
+struct Scaled {
+  denominator: i128,
+  particular: Vec<i128>,
+  null_space: Vec<Vec<i128>>,
+}
+
+impl Scaled {
+  fn new(space: &SolutionSpace) -> Option<Self> {
+    let denominator = space
+      .particular
+      .iter()
+      .chain(space.null_space.iter().flatten())
+      .try_fold(1i128, |denominator, value| {
+        lcm(denominator, value.denominator())
+      })?;
+    let scale = |values: &[Rational]| {
+      values
+        .iter()
+        .map(|value| {
+          value
+            .numerator()
+            .checked_mul(denominator.checked_div(value.denominator())?)
+        })
+        .collect::<Option<Vec<_>>>()
+    };
+
+    Some(Self {
+      denominator,
+      particular: scale(&space.particular)?,
+      null_space: space
+        .null_space
+        .iter()
+        .map(|vector| scale(vector))
+        .collect::<Option<Vec<_>>>()?,
+    })
+  }
+
+  /// Integer solution with the free variables set to `values` if it is one
+  /// and stays within `bounds`.
+  fn solution(
+    &self,
+    values: &[i128],
+    bounds: &[RangeInclusive<i128>],
+  ) -> Option<Vec<i128>> {
+    self
+      .particular
+      .iter()
+      .zip(bounds.iter())
+      .enumerate()
+      .map(|(variable, (&numerator, bound))| {
+        let numerator = self.null_space.iter().zip(values.iter()).try_fold(
+          numerator,
+          |numerator, (vector, value)| {
+            numerator.checked_add(vector.get(variable)?.checked_mul(*value)?)
+          },
+        )?;
+        if numerator.checked_rem(self.denominator)? != 0 {
+          return None;
+        }
+        numerator
+          .checked_div(self.denominator)
+          .filter(|value| bound.contains(value))
+      })
+      .collect::<Option<Vec<_>>>()
+      .filter(|solution| solution.len() == self.particular.len())
+  }
+}
+
+/// Every combination of values within the ranges, the last one turning
+/// fastest.
+struct Odometer {
+  ranges: Vec<RangeInclusive<i128>>,
+  next: Option<Vec<i128>>,
+}
+
+impl Odometer {
+  fn new(ranges: Vec<RangeInclusive<i128>>) -> Self {
+    let next = ranges
+      .iter()
+      .map(|range| (!range.is_empty()).then_some(*range.start()))
+      .collect();
+    Self { ranges, next }
+  }
+}
+
+impl Iterator for Odometer {
+  type Item = Vec<i128>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.next.take()?;
+
+    let mut next = current.clone();
+    for (value, range) in next.iter_mut().zip(self.ranges.iter()).rev() {
+      match value.checked_add(1).filter(|value| range.contains(value)) {
+        Some(turned) => {
+          *value = turned;
+          self.next = Some(next);
+          break;
+        }
+        None => *value = *range.start(),
+      }
+    }
+
+    Some(current)
+  }
+}
+
+/// Every solution of a linear system over GF(2) with variables packed into
+/// the bits of a mask.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gf2SolutionSpace {
+  particular: u128,
+  null_space: Vec<u128>,
+}
+
+/// Solves equations over GF(2), each being the mask of variables that xor
+/// up to the bit next to it.
+///
+/// Nothing when the system has no solution or there are more than 128
+/// variables.
+pub fn solve_gf2(
+  equations: impl IntoIterator<Item = (u128, bool)>,
+  variables: u32,
+) -> Option<Gf2SolutionSpace> {
+  if variables > u128::BITS {
+    return None;
+  }
+
+  let mut rows = equations.into_iter().collect::<Vec<_>>();
+  let mut pivots = Vec::new();
+  for variable in 0..variables {
+    let bit = 1u128.checked_shl(variable)?;
+    let rank = pivots.len();
+    let Some(found) = (rank..rows.len())
+      .find(|&index| rows.get(index).is_some_and(|&(mask, _)| mask & bit != 0))
+    else {
+      continue;
+    };
+    rows.swap(rank, found);
+
+    let (pivot_mask, pivot_constant) = *rows.get(rank)?;
+    for (index, (mask, constant)) in rows.iter_mut().enumerate() {
+      if index != rank && *mask & bit != 0 {
+        *mask ^= pivot_mask;
+        *constant ^= pivot_constant;
+      }
+    }
+    pivots.push(bit);
+  }
+
+  if rows
+    .iter()
+    .skip(pivots.len())
+    .any(|&(mask, constant)| mask == 0 && constant)
+  {
+    return None;
+  }
+
+  let particular = rows
+    .iter()
+    .zip(pivots.iter())
+    .filter(|((_, constant), _)| *constant)
+    .fold(0, |particular, (_, bit)| particular | bit);
+  let null_space = (0..variables)
+    .filter_map(|variable| 1u128.checked_shl(variable))
+    .filter(|bit| !pivots.contains(bit))
+    .map(|free| {
+      rows
+        .iter()
+        .zip(pivots.iter())
+        .filter(|((mask, _), _)| mask & free != 0)
+        .fold(free, |vector, (_, bit)| vector | bit)
+    })
+    .collect();
+
+  Some(Gf2SolutionSpace {
+    particular,
+    null_space,
+  })
+}
+
+impl Gf2SolutionSpace {
+  pub fn particular(&self) -> u128 {
+    self.particular
+  }
+
+  pub fn null_space(&self) -> &[u128] {
+    &self.null_space
+  }
+
+  /// Every solution, the particular one first.
+  pub fn solutions(&self) -> impl Iterator<Item = u128> + '_ {
+    let last = u128::MAX
+      .checked_shr(u128::BITS.saturating_sub(self.null_space.len() as u32))
+      .unwrap_or_default();
+    (0..=last).map(|combination| {
+      self
+        .null_space
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+          combination.checked_shr(*index as u32).unwrap_or_default() & 1 == 1
+        })
+        .fold(self.particular, |solution, (_, vector)| solution ^ vector)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rationals(values: &[i128]) -> Vec<Rational> {
+    values.iter().copied().map(Rational::integer).collect()
+  }
+
+  #[test]
+  fn solves_rational_systems() {
+    let unique = solve(
+      &[rationals(&[2, 1]), rationals(&[1, 3])],
+      &rationals(&[3, 5]),
+    );
+    let expected = [Rational::new(4, 5), Rational::new(7, 5)];
+    assert_eq!(
+      unique.as_ref().and_then(SolutionSpace::unique),
+      expected.into_iter().collect::<Option<Vec<_>>>().as_deref()
+    );
+
+    // NOTE: x + y = 4 and y + z = 3
+    let space = solve(
+      &[rationals(&[1, 1, 0]), rationals(&[0, 1, 1])],
+      &rationals(&[4, 3]),
+    );
+    assert_eq!(space.as_ref().map(SolutionSpace::free), Some(&[2][..]));
+    let bounds = [0..=3, 0..=3, 0..=3];
+    let solutions = space
+      .iter()
+      .flat_map(|space| space.integer_solutions(&bounds))
+      .collect::<Vec<_>>();
+    assert_eq!(solutions, [vec![1, 3, 0], vec![2, 2, 1], vec![3, 1, 2]]);
+    assert_eq!(
+      space.as_ref().and_then(|space| space.candidates(&bounds)),
+      Some(4)
+    );
+
+    assert_eq!(
+      solve(
+        &[rationals(&[1, 1]), rationals(&[2, 2])],
+        &rationals(&[1, 3])
+      ),
+      None
+    );
+    assert_eq!(solve(&[rationals(&[1, 1])], &rationals(&[1, 3])), None);
+    assert_eq!(
+      solve(&[rationals(&[1, 1]), rationals(&[1])], &rationals(&[1, 3])),
+      None
+    );
+  }
+
+  #[test]
+  fn checks_integer_feasibility() {
+    let feasible = |coefficients: &[&[i128]], constants: &[i128]| {
+      let coefficients = coefficients
+        .iter()
+        .map(|row| rationals(row))
+        .collect::<Vec<_>>();
+      solve(&coefficients, &rationals(constants))
+        .and_then(|space| space.has_integer_solution())
+    };
+
+    assert_eq!(feasible(&[&[1, 1, 0], &[0, 1, 1]], &[4, 3]), Some(true));
+    assert_eq!(feasible(&[&[2, 2]], &[3]), Some(false));
+    assert_eq!(feasible(&[&[2, 1], &[1, 3]], &[3, 5]), Some(false));
+    assert_eq!(feasible(&[&[6, 10]], &[1]), Some(false));
+    assert_eq!(feasible(&[&[6, 10, 15]], &[1]), Some(true));
+    // NOTE: x = 2y + 1 and x = 3z + 2 both hold for x = 5
+    assert_eq!(feasible(&[&[1, -2, 0], &[1, 0, -3]], &[1, 2]), Some(true));
+    // NOTE: 3y + 3z = 1 has rational solutions only
+    assert_eq!(feasible(&[&[2, 4, 0], &[0, 3, 3]], &[2, 1]), Some(false));
+  }
+
+  #[test]
+  fn solves_gf2_systems() {
+    // NOTE: a ^ b = 1 and b ^ c = 0 with d left alone
+    let space = solve_gf2([(0b011, true), (0b110, false)], 4);
+    let mut solutions = space
+      .iter()
+      .flat_map(Gf2SolutionSpace::solutions)
+      .collect::<Vec<_>>();
+    solutions.sort();
+    assert_eq!(solutions, [0b0001, 0b0110, 0b1001, 0b1110]);
+
+    assert_eq!(solve_gf2([(0b1, true), (0b1, false)], 1), None);
+  }
+}
@@ -0,0 +1,186 @@
+use std::fmt::Display;
+
+use crate::{
+  mod_inverse, mul_mod,
+  number::{add_mod, modulo, sub_mod},
+  Integer,
+};
+
+/// Integer modulo a modulus picked at runtime, always within `0..modulus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Modular<T> {
+  value: T,
+  modulus: T,
+}
+
+impl<T: Integer> Modular<T> {
+  /// Nothing unless `modulus` is positive.
+  pub fn new(value: T, modulus: T) -> Option<Self> {
+    if modulus <= T::ZERO {
+      return None;
+    }
+
+    Some(Self {
+      value: modulo(value, modulus)?,
+      modulus,
+    })
+  }
+
+  pub fn value(&self) -> T {
+    self.value
+  }
+
+  pub fn modulus(&self) -> T {
+    self.modulus
+  }
+
+  pub fn checked_add(self, other: T) -> Option<Self> {
+    let other = modulo(other, self.modulus)?;
+    self.with(add_mod(self.value, other, self.modulus)?)
+  }
+
+  pub fn checked_sub(self, other: T) -> Option<Self> {
+    let other = modulo(other, self.modulus)?;
+    self.with(sub_mod(self.value, other, self.modulus)?)
+  }
+
+  pub fn checked_mul(self, other: T) -> Option<Self> {
+    self.with(mul_mod(self.value, other, self.modulus)?)
+  }
+
+  pub fn checked_neg(self) -> Option<Self> {
+    self.with(sub_mod(T::ZERO, self.value, self.modulus)?)
+  }
+
+  pub fn checked_pow(self, exp: u32) -> Option<Self> {
+    let mut power = self.with(modulo(T::ONE, self.modulus)?)?;
+    let mut base = self;
+    let mut exp = exp;
+    while exp != 0 {
+      if exp & 1 == 1 {
+        power = power.checked_mul(base.value)?;
+      }
+      base = base.checked_mul(base.value)?;
+      exp >>= 1;
+    }
+    Some(power)
+  }
+
+  /// Number that gives one when multiplied with this one.
+  pub fn inverse(self) -> Option<Self> {
+    self.with(mod_inverse(self.value, self.modulus)?)
+  }
+
+  /// Counts up by `steps` along with how many times it wrapped from the last
+  /// value back to zero.
+  pub fn forward(self, steps: T) -> Option<(Self, T)> {
+    if steps < T::ZERO {
+      return None;
+    }
+
+    let laps = steps.checked_div(self.modulus)?;
+    let rest = steps.checked_rem(self.modulus)?;
+    let room = self.modulus.checked_sub(rest)?;
+    if self.value >= room {
+      Some((
+        self.with(self.value.checked_sub(room)?)?,
+        laps.checked_add(T::ONE)?,
+      ))
+    } else {
+      Some((self.with(self.value.checked_add(rest)?)?, laps))
+    }
+  }
+
+  /// Counts down by `steps` along with how many times it wrapped from zero
+  /// back to the last value.
+  pub fn backward(self, steps: T) -> Option<(Self, T)> {
+    if steps < T::ZERO {
+      return None;
+    }
+
+    let laps = steps.checked_div(self.modulus)?;
+    let rest = steps.checked_rem(self.modulus)?;
+    if self.value < rest {
+      let room = self.modulus.checked_sub(rest)?;
+      Some((
+        self.with(self.value.checked_add(room)?)?,
+        laps.checked_add(T::ONE)?,
+      ))
+    } else {
+      Some((self.with(self.value.checked_sub(rest)?)?, laps))
+    }
+  }
+
+  fn with(self, value: T) -> Option<Self> {
+    (T::ZERO <= value && value < self.modulus).then_some(Self { value, ..self })
+  }
+}
+
+impl<T: Display> Display for Modular<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} (mod {})", self.value, self.modulus)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  #[test]
+  fn does_arithmetic() {
+    let value = Modular::new(-3i64, 7);
+    assert_eq!(value.map(|value| value.value()), Some(4));
+    assert_eq!(
+      value
+        .and_then(|value| value.checked_pow(3))
+        .map(|value| value.value()),
+      Some(1)
+    );
+    assert_eq!(
+      value.and_then(Modular::inverse).map(|value| value.value()),
+      Some(2)
+    );
+    assert_eq!(Modular::new(1u8, 0), None);
+  }
+
+  proptest! {
+    #[test]
+    fn counts_wraps_like_stepping(
+      modulus in 1u16..50,
+      start in 0u16..50,
+      steps in 0u16..500,
+    ) {
+      let dial = Modular::new(start, modulus);
+
+      let start = start.checked_rem(modulus).unwrap_or_default();
+      let (mut up, mut up_wraps) = (start, 0u16);
+      let (mut down, mut down_wraps) = (start, 0u16);
+      for _ in 0..steps {
+        up = up.saturating_add(1);
+        if up == modulus {
+          up = 0;
+          up_wraps = up_wraps.saturating_add(1);
+        }
+        if down == 0 {
+          down = modulus;
+          down_wraps = down_wraps.saturating_add(1);
+        }
+        down = down.saturating_sub(1);
+      }
+
+      prop_assert_eq!(
+        dial.and_then(|dial| dial.forward(steps)),
+        Modular::new(up, modulus).map(|up| (up, up_wraps))
+      );
+      prop_assert_eq!(
+        dial.and_then(|dial| dial.backward(steps)),
+        Modular::new(down, modulus).map(|down| (down, down_wraps))
+      );
+      prop_assert_eq!(
+        dial.and_then(|dial| dial.checked_sub(steps)),
+        Modular::new(down, modulus)
+      );
+    }
+  }
+}
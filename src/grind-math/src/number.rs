@@ -0,0 +1,307 @@
+use crate::{Integer, Signed};
+
+/// Ten to the power of `exp` if it fits.
+pub fn pow10<T: Integer>(exp: u32) -> Option<T> {
+  T::TEN.checked_pow(exp)
+}
+
+/// Decimal digits of `value` without its sign, zero having one digit.
+pub fn digit_count<T: Integer>(value: T) -> u32 {
+  let mut count = 1u32;
+  let mut rest = value.checked_div(T::TEN).unwrap_or_default();
+  while rest != T::ZERO {
+    count = count.saturating_add(1);
+    rest = rest.checked_div(T::TEN).unwrap_or_default();
+  }
+  count
+}
+
+/// Splits off the lowest `low_digits` decimal digits, 1234 split at 1 being
+/// 123 and 4.
+pub fn split_digits<T: Integer>(value: T, low_digits: u32) -> (T, T) {
+  let Some(shift) = pow10(low_digits) else {
+    return (T::ZERO, value);
+  };
+  (
+    value.checked_div(shift).unwrap_or_default(),
+    value.checked_rem(shift).unwrap_or_default(),
+  )
+}
+
+/// Writes the digits of `low` after the digits of `high`, 12 and 34 making
+/// 1234.
+pub fn concat_digits<T: Integer>(high: T, low: T) -> Option<T> {
+  if low < T::ZERO {
+    return None;
+  }
+
+  let shifted = high.checked_mul(pow10(digit_count(low))?)?;
+  if high < T::ZERO {
+    shifted.checked_sub(low)
+  } else {
+    shifted.checked_add(low)
+  }
+}
+
+/// Greatest common divisor, never negative and saturating when it is the
+/// magnitude of a signed minimum.
+pub fn gcd<T: Integer>(lhs: T, rhs: T) -> T {
+  let (mut lhs, mut rhs) = (lhs, rhs);
+  while rhs != T::ZERO {
+    let rest = lhs.checked_rem(rhs).unwrap_or_default();
+    lhs = rhs;
+    rhs = rest;
+  }
+
+  if lhs < T::ZERO {
+    lhs.checked_neg().unwrap_or(T::MAX)
+  } else {
+    lhs
+  }
+}
+
+/// Least common multiple, never negative and zero when either side is.
+pub fn lcm<T: Integer>(lhs: T, rhs: T) -> Option<T> {
+  if lhs == T::ZERO || rhs == T::ZERO {
+    return Some(T::ZERO);
+  }
+
+  let lcm = lhs.checked_div(gcd(lhs, rhs))?.checked_mul(rhs)?;
+  if lcm < T::ZERO {
+    lcm.checked_neg()
+  } else {
+    Some(lcm)
+  }
+}
+
+/// Greatest common divisor with `x` and `y` so that `lhs * x + rhs * y` is
+/// the divisor.
+pub fn extended_gcd<T: Signed>(lhs: T, rhs: T) -> Option<(T, T, T)> {
+  let bezout = Bezout::new(abs(lhs)?, abs(rhs)?)?;
+  let (x, y) = bezout.coefficients()?;
+  let x = if lhs < T::ZERO { x.checked_neg()? } else { x };
+  let y = if rhs < T::ZERO { y.checked_neg()? } else { y };
+
+  Some((bezout.gcd, x, y))
+}
+
+/// Number that gives one when multiplied with `value` modulo `modulus`.
+pub fn mod_inverse<T: Integer>(value: T, modulus: T) -> Option<T> {
+  if modulus <= T::ZERO {
+    return None;
+  }
+
+  let bezout = Bezout::new(modulo(value, modulus)?, modulus)?;
+  if bezout.gcd != T::ONE {
+    return None;
+  }
+
+  let inverse = if bezout.lhs_negative {
+    modulus.checked_sub(bezout.lhs)?
+  } else {
+    bezout.lhs
+  };
+  modulo(inverse, modulus)
+}
+
+/// Product modulo `modulus` that does not overflow for moduli that fit.
+pub fn mul_mod<T: Integer>(lhs: T, rhs: T, modulus: T) -> Option<T> {
+  if modulus <= T::ZERO {
+    return None;
+  }
+
+  let mut lhs = modulo(lhs, modulus)?;
+  let mut rhs = modulo(rhs, modulus)?;
+  if let Some(product) = lhs.checked_mul(rhs) {
+    return product.checked_rem(modulus);
+  }
+
+  let mut product = T::ZERO;
+  while rhs != T::ZERO {
+    if rhs.checked_rem(T::TWO)? == T::ONE {
+      product = add_mod(product, lhs, modulus)?;
+    }
+    lhs = add_mod(lhs, lhs, modulus)?;
+    rhs = rhs.checked_div(T::TWO)?;
+  }
+  Some(product)
+}
+
+/// Smallest non-negative number matching every residue and modulus pair
+/// together with the modulus the solutions repeat at.
+///
+/// Moduli don't have to be coprime.
+pub fn chinese_remainder<T: Integer>(
+  congruences: impl IntoIterator<Item = (T, T)>,
+) -> Option<(T, T)> {
+  let mut solution = (T::ZERO, T::ONE);
+  for (residue, modulus) in congruences {
+    if modulus <= T::ZERO {
+      return None;
+    }
+
+    let (current, current_modulus) = solution;
+    let divisor = gcd(current_modulus, modulus);
+    let difference = sub_mod(
+      modulo(residue, modulus)?,
+      modulo(current, modulus)?,
+      modulus,
+    )?;
+    if difference.checked_rem(divisor)? != T::ZERO {
+      return None;
+    }
+
+    let step_modulus = modulus.checked_div(divisor)?;
+    let steps = mul_mod(
+      difference.checked_div(divisor)?,
+      mod_inverse(current_modulus.checked_div(divisor)?, step_modulus)?,
+      step_modulus,
+    )?;
+    solution = (
+      current.checked_add(current_modulus.checked_mul(steps)?)?,
+      current_modulus.checked_mul(step_modulus)?,
+    );
+  }
+
+  Some(solution)
+}
+
+/// Largest number whose square is at most `value`.
+pub fn isqrt<T: Integer>(value: T) -> Option<T> {
+  if value < T::ZERO {
+    return None;
+  }
+
+  let (mut low, mut high) = (T::ZERO, value);
+  while low < high {
+    let middle =
+      high.checked_sub(high.checked_sub(low)?.checked_div(T::TWO)?)?;
+    if middle
+      .checked_mul(middle)
+      .is_some_and(|square| square <= value)
+    {
+      low = middle;
+    } else {
+      high = middle.checked_sub(T::ONE)?;
+    }
+  }
+
+  Some(low)
+}
+
+/// Extended Euclid over non-negative numbers.
+///
+/// Coefficients alternate in sign along the way so only their magnitudes are
+/// kept, which keeps this working for unsigned numbers too.
+struct Bezout<T> {
+  gcd: T,
+  lhs: T,
+  rhs: T,
+  lhs_negative: bool,
+}
+
+impl<T: Integer> Bezout<T> {
+  fn new(lhs: T, rhs: T) -> Option<Self> {
+    let (mut remainder, mut next_remainder) = (lhs, rhs);
+    let (mut lhs, mut next_lhs) = (T::ONE, T::ZERO);
+    let (mut rhs, mut next_rhs) = (T::ZERO, T::ONE);
+    let mut lhs_negative = false;
+
+    while next_remainder != T::ZERO {
+      let quotient = remainder.checked_div(next_remainder)?;
+      (remainder, next_remainder) =
+        (next_remainder, remainder.checked_rem(next_remainder)?);
+      (lhs, next_lhs) =
+        (next_lhs, lhs.checked_add(quotient.checked_mul(next_lhs)?)?);
+      (rhs, next_rhs) =
+        (next_rhs, rhs.checked_add(quotient.checked_mul(next_rhs)?)?);
+      lhs_negative = !lhs_negative;
+    }
+
+    Some(Self {
+      gcd: remainder,
+      lhs,
+      rhs,
+      lhs_negative,
+    })
+  }
+
+  fn coefficients(&self) -> Option<(T, T)> {
+    if self.lhs_negative {
+      Some((self.lhs.checked_neg()?, self.rhs))
+    } else {
+      Some((self.lhs, self.rhs.checked_neg()?))
+    }
+  }
+}
+
+fn abs<T: Integer>(value: T) -> Option<T> {
+  if value < T::ZERO {
+    value.checked_neg()
+  } else {
+    Some(value)
+  }
+}
+
+pub(crate) fn modulo<T: Integer>(value: T, modulus: T) -> Option<T> {
+  let rest = value.checked_rem(modulus)?;
+  if rest < T::ZERO {
+    rest.checked_add(modulus)
+  } else {
+    Some(rest)
+  }
+}
+
+pub(crate) fn add_mod<T: Integer>(lhs: T, rhs: T, modulus: T) -> Option<T> {
+  let room = modulus.checked_sub(rhs)?;
+  if lhs >= room {
+    lhs.checked_sub(room)
+  } else {
+    lhs.checked_add(rhs)
+  }
+}
+
+pub(crate) fn sub_mod<T: Integer>(lhs: T, rhs: T, modulus: T) -> Option<T> {
+  if lhs >= rhs {
+    lhs.checked_sub(rhs)
+  } else {
+    modulus.checked_sub(rhs.checked_sub(lhs)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn works_with_digits() {
+    assert_eq!([0, 9, 10, 12345].map(digit_count), [1, 1, 2, 5]);
+    assert_eq!(digit_count(i8::MIN), 3);
+    assert_eq!(split_digits(12345u64, 2), (123, 45));
+    assert_eq!(split_digits(7u8, 5), (0, 7));
+    assert_eq!(concat_digits(12u64, 345), Some(12345));
+    assert_eq!(concat_digits(-12i32, 0), Some(-120));
+    assert_eq!(concat_digits(255u8, 1), None);
+  }
+
+  #[test]
+  fn solves_congruences() {
+    assert_eq!((gcd(12, -18), lcm(4u8, 6)), (6, Some(12)));
+    assert_eq!(extended_gcd(240, 46), Some((2, -9, 47)));
+    assert_eq!(extended_gcd(-3i8, 0), Some((3, -1, 0)));
+    assert_eq!(mod_inverse(3u64, 11), Some(4));
+    assert_eq!(mod_inverse(-3i64, 11), Some(7));
+    assert_eq!(mod_inverse(4u64, 8), None);
+    assert_eq!(mul_mod(u128::MAX - 1, u128::MAX - 1, u128::MAX), Some(1));
+    assert_eq!(
+      chinese_remainder([(2u64, 3), (3, 5), (2, 7)]),
+      Some((23, 105))
+    );
+    assert_eq!(chinese_remainder([(2u8, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(chinese_remainder([(1u8, 4), (2, 6)]), None);
+    assert_eq!(
+      [0, 15, 16, u64::MAX].map(isqrt),
+      [0, 3, 4, 4294967295].map(Some)
+    );
+  }
+}
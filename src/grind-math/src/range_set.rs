@@ -0,0 +1,213 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use crate::Integer;
+
+/// Integers covered by sorted inclusive ranges that neither overlap nor
+/// touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet<T> {
+  ranges: Vec<(T, T)>,
+}
+
+impl<T: Integer> RangeSet<T> {
+  pub fn new() -> Self {
+    Self { ranges: Vec::new() }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+
+  /// Merged ranges from the smallest.
+  pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+    self.ranges.iter().map(|&(start, end)| start..=end)
+  }
+
+  /// How many integers are covered, saturating at the maximum.
+  pub fn covered(&self) -> T {
+    self.ranges.iter().fold(T::ZERO, |covered, &(start, end)| {
+      covered.saturating_add(end.saturating_sub(start).saturating_add(T::ONE))
+    })
+  }
+
+  pub fn contains(&self, value: T) -> bool {
+    let index = self.ranges.partition_point(|&(_, end)| end < value);
+    self
+      .ranges
+      .get(index)
+      .is_some_and(|&(start, _)| start <= value)
+  }
+
+  /// Merges `range` with every range it overlaps or touches.
+  pub fn insert(&mut self, range: RangeInclusive<T>) {
+    let (mut start, mut end) = range.into_inner();
+    if start > end {
+      return;
+    }
+
+    let first = self
+      .ranges
+      .partition_point(|&(_, other_end)| !touches(other_end, start));
+    let last = self
+      .ranges
+      .partition_point(|&(other_start, _)| touches(end, other_start));
+    if let Some(&(other_start, _)) = self.ranges.get(first) {
+      start = start.min(other_start);
+    }
+    if let Some(&(_, other_end)) =
+      last.checked_sub(1).and_then(|last| self.ranges.get(last))
+    {
+      end = end.max(other_end);
+    }
+
+    self.ranges.splice(first..last.max(first), [(start, end)]);
+  }
+
+  /// Cuts `range` out of every range it overlaps.
+  pub fn remove(&mut self, range: RangeInclusive<T>) {
+    let (start, end) = range.into_inner();
+    if start > end {
+      return;
+    }
+
+    let first = self
+      .ranges
+      .partition_point(|&(_, other_end)| other_end < start);
+    let last = self
+      .ranges
+      .partition_point(|&(other_start, _)| other_start <= end);
+    let mut kept = Vec::new();
+    if let Some(&(other_start, _)) = self.ranges.get(first) {
+      if let Some(before) =
+        start.checked_sub(T::ONE).filter(|_| other_start < start)
+      {
+        kept.push((other_start, before));
+      }
+    }
+    if let Some(&(_, other_end)) =
+      last.checked_sub(1).and_then(|last| self.ranges.get(last))
+    {
+      if let Some(after) = end.checked_add(T::ONE).filter(|_| other_end > end) {
+        kept.push((after, other_end));
+      }
+    }
+
+    self.ranges.splice(first..last.max(first), kept);
+  }
+
+  pub fn union(&self, other: &Self) -> Self {
+    let mut union = self.clone();
+    for range in other.ranges() {
+      union.insert(range);
+    }
+    union
+  }
+
+  pub fn intersection(&self, other: &Self) -> Self {
+    let mut ranges = Vec::new();
+    let mut lhs = self.ranges.iter().peekable();
+    let mut rhs = other.ranges.iter().peekable();
+    while let (Some(&&(lhs_start, lhs_end)), Some(&&(rhs_start, rhs_end))) =
+      (lhs.peek(), rhs.peek())
+    {
+      let start = lhs_start.max(rhs_start);
+      let end = lhs_end.min(rhs_end);
+      if start <= end {
+        ranges.push((start, end));
+      }
+      if lhs_end < rhs_end {
+        lhs.next();
+      } else {
+        rhs.next();
+      }
+    }
+    Self { ranges }
+  }
+
+  pub fn difference(&self, other: &Self) -> Self {
+    let mut difference = self.clone();
+    for range in other.ranges() {
+      difference.remove(range);
+    }
+    difference
+  }
+
+  /// Integers within `bounds` that are not covered.
+  pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+    Self::from(bounds).difference(self)
+  }
+}
+
+/// Whether a range ending at `end` overlaps or touches one starting at
+/// `start`.
+fn touches<T: Integer>(end: T, start: T) -> bool {
+  start <= end || end.checked_add(T::ONE) == Some(start)
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for RangeSet<T> {
+  fn from(range: RangeInclusive<T>) -> Self {
+    let mut set = Self::new();
+    set.insert(range);
+    set
+  }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+  fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+    let mut set = Self::new();
+    for range in iter {
+      set.insert(range);
+    }
+    set
+  }
+}
+
+impl<T: Integer> Display for RangeSet<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (index, (start, end)) in self.ranges.iter().enumerate() {
+      if index != 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{start}..={end}")?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn merges_and_cuts() {
+    let mut set = [10..=14, 3..=5, 16..=20, 12..=18, 6..=6]
+      .into_iter()
+      .collect::<RangeSet<u64>>();
+    assert_eq!(set.to_string(), "3..=6, 10..=20");
+    assert_eq!(set.covered(), 15);
+    assert!(set.contains(6) && !set.contains(7) && set.contains(20));
+
+    set.remove(12..=13);
+    set.remove(0..=3);
+    assert_eq!(set.to_string(), "4..=6, 10..=11, 14..=20");
+  }
+
+  #[test]
+  fn combines_sets() {
+    let lhs = [0..=10, 20..=30].into_iter().collect::<RangeSet<i128>>();
+    let rhs = [5..=25].into_iter().collect::<RangeSet<i128>>();
+
+    assert_eq!(lhs.union(&rhs).to_string(), "0..=30");
+    assert_eq!(lhs.intersection(&rhs).to_string(), "5..=10, 20..=25");
+    assert_eq!(lhs.difference(&rhs).to_string(), "0..=4, 26..=30");
+    assert_eq!(
+      lhs.complement(-5..=35).to_string(),
+      "-5..=-1, 11..=19, 31..=35"
+    );
+    assert_eq!(
+      RangeSet::from(0..=u128::MAX).complement(0..=u128::MAX),
+      RangeSet::new()
+    );
+  }
+}
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+
+use crate::{gcd, Integer};
+
+/// Exact fraction kept in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+  numerator: i128,
+  denominator: i128,
+}
+
+impl Rational {
+  pub const ZERO: Self = Self::integer(0);
+  pub const ONE: Self = Self::integer(1);
+
+  /// Nothing when `denominator` is zero or the fraction does not fit.
+  pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+    if denominator == 0 {
+      return None;
+    }
+
+    let divisor = gcd(numerator, denominator);
+    let numerator = numerator.checked_div(divisor)?;
+    let denominator = denominator.checked_div(divisor)?;
+    if denominator < 0 {
+      Some(Self {
+        numerator: numerator.checked_neg()?,
+        denominator: denominator.checked_neg()?,
+      })
+    } else {
+      Some(Self {
+        numerator,
+        denominator,
+      })
+    }
+  }
+
+  pub const fn integer(value: i128) -> Self {
+    Self {
+      numerator: value,
+      denominator: 1,
+    }
+  }
+
+  pub fn numerator(&self) -> i128 {
+    self.numerator
+  }
+
+  pub fn denominator(&self) -> i128 {
+    self.denominator
+  }
+
+  pub fn is_zero(&self) -> bool {
+    self.numerator == 0
+  }
+
+  pub fn is_integer(&self) -> bool {
+    self.denominator == 1
+  }
+
+  pub fn to_integer(&self) -> Option<i128> {
+    self.is_integer().then_some(self.numerator)
+  }
+
+  pub fn checked_neg(self) -> Option<Self> {
+    Some(Self {
+      numerator: self.numerator.checked_neg()?,
+      ..self
+    })
+  }
+
+  pub fn checked_recip(self) -> Option<Self> {
+    Self::new(self.denominator, self.numerator)
+  }
+
+  pub fn checked_add(self, other: Self) -> Option<Self> {
+    // NOTE: going over the least common denominator keeps the intermediate
+    // products as small as they can be
+    let divisor = gcd(self.denominator, other.denominator);
+    let lhs_scale = other.denominator.checked_div(divisor)?;
+    let rhs_scale = self.denominator.checked_div(divisor)?;
+    Self::new(
+      self
+        .numerator
+        .checked_mul(lhs_scale)?
+        .checked_add(other.numerator.checked_mul(rhs_scale)?)?,
+      self.denominator.checked_mul(lhs_scale)?,
+    )
+  }
+
+  pub fn checked_sub(self, other: Self) -> Option<Self> {
+    self.checked_add(other.checked_neg()?)
+  }
+
+  pub fn checked_mul(self, other: Self) -> Option<Self> {
+    let lhs_divisor = gcd(self.numerator, other.denominator).max(1);
+    let rhs_divisor = gcd(other.numerator, self.denominator).max(1);
+    Self::new(
+      self
+        .numerator
+        .checked_div(lhs_divisor)?
+        .checked_mul(other.numerator.checked_div(rhs_divisor)?)?,
+      self
+        .denominator
+        .checked_div(rhs_divisor)?
+        .checked_mul(other.denominator.checked_div(lhs_divisor)?)?,
+    )
+  }
+
+  pub fn checked_div(self, other: Self) -> Option<Self> {
+    self.checked_mul(other.checked_recip()?)
+  }
+}
+
+impl Default for Rational {
+  fn default() -> Self {
+    Self::ZERO
+  }
+}
+
+impl<T: Integer + Into<i128>> From<T> for Rational {
+  fn from(value: T) -> Self {
+    Self::integer(value.into())
+  }
+}
+
+impl Display for Rational {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.is_integer() {
+      write!(f, "{}", self.numerator)
+    } else {
+      write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn stays_in_lowest_terms() {
+    let half = Rational::new(2, -4);
+    let third = Rational::new(1, 3);
+
+    assert_eq!(half.map(|half| half.to_string()).as_deref(), Some("-1/2"));
+    assert_eq!(
+      half
+        .zip(third)
+        .and_then(|(half, third)| half.checked_sub(third))
+        .map(|difference| difference.to_string())
+        .as_deref(),
+      Some("-5/6")
+    );
+    assert_eq!(
+      third.and_then(|third| third.checked_mul(Rational::from(6u8))),
+      Some(Rational::integer(2))
+    );
+    assert_eq!(Rational::new(1, 0), None);
+    assert_eq!(Rational::ZERO.checked_recip(), None);
+  }
+}
@@ -0,0 +1,66 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Context;
+use grind_core::{Day, Part, Year};
+use serde::{Deserialize, Serialize};
+
+/// Answers confirmed correct, keyed by year and zero padded day.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, DayAnswers>>);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DayAnswers {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  part1: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  part2: Option<String>,
+}
+
+impl Answers {
+  pub fn load(path: &Path) -> anyhow::Result<Self> {
+    if !path.exists() {
+      return Ok(Self::default());
+    }
+
+    let text = std::fs::read_to_string(path).with_context(|| {
+      format!("Failed reading answers from {}", path.display())
+    })?;
+    toml::from_str(&text)
+      .with_context(|| format!("Invalid answers in {}", path.display()))
+  }
+
+  pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+    let text = toml::to_string(self)?;
+    std::fs::write(path, text)
+      .with_context(|| format!("Failed writing answers to {}", path.display()))
+  }
+
+  pub fn get(&self, year: Year, day: Day, part: Part) -> Option<&str> {
+    let answers = self.0.get(&year.to_string())?.get(&day_key(day))?;
+    match part {
+      Part::One => answers.part1.as_deref(),
+      Part::Two => answers.part2.as_deref(),
+    }
+  }
+
+  pub fn set(&mut self, year: Year, day: Day, part: Part, answer: String) {
+    let answers = self
+      .0
+      .entry(year.to_string())
+      .or_default()
+      .entry(day_key(day))
+      .or_default();
+    match part {
+      Part::One => answers.part1 = Some(answer),
+      Part::Two => answers.part2 = Some(answer),
+    }
+  }
+}
+
+fn day_key(day: Day) -> String {
+  format!("{day:02}")
+}
+
+pub const ANSWERS_PATH: &str =
+  concat!(env!("CARGO_MANIFEST_DIR"), "/../../answers.toml");
@@ -0,0 +1,246 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+use anyhow::Context;
+use grind_core::{Day, Part, Year};
+use serde::{Deserialize, Serialize};
+
+/// Every answer submitted so far, keyed by `year/day/part`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Attempts(BTreeMap<String, Vec<Attempt>>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+  pub answer: String,
+  #[serde(flatten)]
+  pub outcome: Outcome,
+  /// Seconds since the unix epoch
+  pub at: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Outcome {
+  Correct,
+  TooHigh,
+  TooLow,
+  Wrong,
+  Wait { seconds: u64 },
+}
+
+impl Attempts {
+  pub fn load(path: &Path) -> anyhow::Result<Self> {
+    if !path.exists() {
+      return Ok(Self::default());
+    }
+
+    let text = std::fs::read_to_string(path).with_context(|| {
+      format!("Failed reading attempts from {}", path.display())
+    })?;
+    serde_json::from_str(&text)
+      .with_context(|| format!("Invalid attempts in {}", path.display()))
+  }
+
+  pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)
+        .with_context(|| format!("Failed creating {}", parent.display()))?;
+    }
+    let text = serde_json::to_string_pretty(self)?;
+    std::fs::write(path, text)
+      .with_context(|| format!("Failed writing attempts to {}", path.display()))
+  }
+
+  pub fn get(&self, year: Year, day: Day, part: Part) -> &[Attempt] {
+    self
+      .0
+      .get(&key(year, day, part))
+      .map(Vec::as_slice)
+      .unwrap_or_default()
+  }
+
+  pub fn push(&mut self, year: Year, day: Day, part: Part, attempt: Attempt) {
+    self
+      .0
+      .entry(key(year, day, part))
+      .or_default()
+      .push(attempt);
+  }
+
+  /// Why `answer` should not be submitted going by earlier attempts.
+  pub fn refusal(
+    &self,
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &str,
+    now: u64,
+  ) -> Option<String> {
+    let attempts = self.get(year, day, part);
+
+    if let Some(correct) = attempts
+      .iter()
+      .find(|attempt| attempt.outcome == Outcome::Correct)
+    {
+      return Some(format!("already solved with {}", correct.answer));
+    }
+
+    if let Some(until) = attempts
+      .iter()
+      .filter_map(|attempt| match attempt.outcome {
+        Outcome::Wait { seconds } => Some(attempt.at.saturating_add(seconds)),
+        _ => None,
+      })
+      .max()
+      .filter(|&until| until > now)
+    {
+      return Some(format!("wait {}s first", until.saturating_sub(now)));
+    }
+
+    if attempts
+      .iter()
+      .any(|attempt| attempt.answer == answer && attempt.outcome.is_wrong())
+    {
+      return Some("known to be wrong".to_owned());
+    }
+
+    let value = answer.trim().parse::<i128>().ok()?;
+    let bound = |outcome: Outcome| {
+      attempts
+        .iter()
+        .filter(move |attempt| attempt.outcome == outcome)
+        .filter_map(|attempt| attempt.answer.trim().parse::<i128>().ok())
+    };
+    if let Some(high) =
+      bound(Outcome::TooHigh).min().filter(|&high| value >= high)
+    {
+      return Some(format!("{high} is already too high"));
+    }
+    if let Some(low) = bound(Outcome::TooLow).max().filter(|&low| value <= low)
+    {
+      return Some(format!("{low} is already too low"));
+    }
+
+    None
+  }
+}
+
+impl Outcome {
+  /// Reads the verdict out of the page the server answers a submission with.
+  pub fn parse(page: &str) -> anyhow::Result<Self> {
+    if page.contains("That's the right answer") {
+      return Ok(Outcome::Correct);
+    }
+
+    if page.contains("You gave an answer too recently") {
+      let seconds = wait_seconds(page).ok_or_else(|| {
+        anyhow::anyhow!("Server asked to wait without saying how long")
+      })?;
+      return Ok(Outcome::Wait { seconds });
+    }
+
+    if page.contains("That's not the right answer") {
+      return Ok(if page.contains("your answer is too high") {
+        Outcome::TooHigh
+      } else if page.contains("your answer is too low") {
+        Outcome::TooLow
+      } else {
+        Outcome::Wrong
+      });
+    }
+
+    if page.contains("You don't seem to be solving the right level") {
+      return Err(anyhow::anyhow!("Part is already solved or still locked"));
+    }
+
+    Err(anyhow::anyhow!("Unexpected answer from the server"))
+  }
+
+  pub fn is_wrong(self) -> bool {
+    matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+  }
+}
+
+impl Display for Outcome {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Outcome::Correct => write!(f, "correct"),
+      Outcome::TooHigh => write!(f, "too high"),
+      Outcome::TooLow => write!(f, "too low"),
+      Outcome::Wrong => write!(f, "wrong"),
+      Outcome::Wait { seconds } => write!(f, "not checked, wait {seconds}s"),
+    }
+  }
+}
+
+// NOTE: the page says something like "You have 1m 5s left to wait."
+fn wait_seconds(page: &str) -> Option<u64> {
+  let (_, rest) = page.split_once("You have ")?;
+  let (wait, _) = rest.split_once(" left to wait")?;
+
+  wait.split_whitespace().try_fold(0u64, |seconds, amount| {
+    let (value, unit) = if let Some(value) = amount.strip_suffix('h') {
+      (value, 60 * 60)
+    } else if let Some(value) = amount.strip_suffix('m') {
+      (value, 60)
+    } else {
+      (amount.strip_suffix('s')?, 1)
+    };
+    Some(
+      seconds.saturating_add(value.parse::<u64>().ok()?.saturating_mul(unit)),
+    )
+  })
+}
+
+fn key(year: Year, day: Day, part: Part) -> String {
+  format!("{year}/{day:02}/{part}")
+}
+
+pub const ATTEMPTS_NAME: &str = "attempts.json";
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_outcomes() -> anyhow::Result<()> {
+    let wrong = "That's not the right answer; your answer is too low.";
+    let wait = "You gave an answer too recently. You have 1m 5s left to wait.";
+
+    assert_eq!(
+      Outcome::parse("That's the right answer!")?,
+      Outcome::Correct
+    );
+    assert_eq!(Outcome::parse(wrong)?, Outcome::TooLow);
+    assert_eq!(Outcome::parse(wait)?, Outcome::Wait { seconds: 65 });
+    assert!(Outcome::parse("<html></html>").is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn refuses_outside_bounds() {
+    let mut attempts = Attempts::default();
+    for (answer, outcome) in
+      [("100", Outcome::TooHigh), ("10", Outcome::TooLow)]
+    {
+      attempts.push(
+        2024,
+        1,
+        Part::One,
+        Attempt {
+          answer: answer.to_owned(),
+          outcome,
+          at: 0,
+        },
+      );
+    }
+    let refusal =
+      |answer: &str| attempts.refusal(2024, 1, Part::One, answer, 1_000);
+
+    assert_eq!(refusal("100"), Some("known to be wrong".to_owned()));
+    assert_eq!(refusal("150"), Some("100 is already too high".to_owned()));
+    assert_eq!(refusal("5"), Some("10 is already too low".to_owned()));
+    assert_eq!(refusal("50"), None);
+  }
+}
@@ -0,0 +1,382 @@
+use std::{
+  collections::BTreeMap,
+  fmt::Display,
+  hint::black_box,
+  path::{Path, PathBuf},
+  time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use grind_core::{input, Day, Part, Puzzle, Year};
+use serde::{Deserialize, Serialize};
+
+use crate::{puzzles, table};
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct BenchArgs {
+  /// Puzzle year, benchmarks every year when left out
+  year: Option<Year>,
+
+  /// Puzzle day, benchmarks every day of the year when left out
+  #[arg(requires = "year")]
+  day: Option<Day>,
+
+  /// Times parse and each part are run
+  #[arg(
+    short = 'n',
+    long,
+    default_value_t = 10,
+    value_parser = clap::value_parser!(u32).range(1..),
+  )]
+  iterations: u32,
+
+  /// Percent a median may grow over the baseline before it is a regression
+  #[arg(short, long, default_value_t = 10)]
+  threshold: u32,
+
+  /// Replace the baseline of every measured stage, not only the new ones
+  #[arg(long, alias = "save")]
+  accept: bool,
+
+  /// Baseline file, defaults to `bench.json` in the repository root
+  #[arg(long, value_name = "PATH")]
+  baseline: Option<PathBuf>,
+}
+
+pub fn bench(args: &BenchArgs) -> anyhow::Result<()> {
+  let baseline_path = args
+    .baseline
+    .clone()
+    .unwrap_or_else(|| PathBuf::from(BASELINE_PATH));
+  let mut baseline = Baseline::load(&baseline_path)?;
+  let mut changed = false;
+
+  let mut rows = Vec::new();
+  for puzzle in puzzles::select(args.year, args.day)? {
+    let measurements = match input::read_file(&input::default_path(puzzle)) {
+      Ok(input) => measure(puzzle, &input, args.iterations),
+      Err(err) => Stage::ALL
+        .into_iter()
+        .map(|stage| (stage, Err(anyhow::anyhow!("{err:#}"))))
+        .collect(),
+    };
+
+    for (stage, stats) in measurements {
+      let key = Baseline::key(puzzle.year, puzzle.day, stage);
+      let previous = baseline.0.get(&key).copied();
+      let status = Status::of(&stats, previous.as_ref(), args.threshold);
+
+      if let Ok(stats) = &stats {
+        if status == Status::New || args.accept {
+          baseline.0.insert(key, *stats);
+          changed = true;
+        }
+      }
+
+      rows.push(Row {
+        year: puzzle.year,
+        day: puzzle.day,
+        stage,
+        status,
+        stats,
+        previous,
+      });
+    }
+  }
+
+  if changed {
+    baseline.save(&baseline_path)?;
+  }
+
+  print_table(&rows);
+
+  let count =
+    |status: Status| rows.iter().filter(|row| row.status == status).count();
+  let failed = count(Status::Fail);
+  let regressed = count(Status::Regressed);
+  println!(
+    "\n{} ok, {regressed} regressed, {} new, {failed} failed",
+    count(Status::Ok),
+    count(Status::New),
+  );
+
+  if failed > 0 || regressed > 0 {
+    return Err(anyhow::anyhow!("{failed} failed, {regressed} regressed"));
+  }
+
+  Ok(())
+}
+
+/// Times parse and both parts `iterations` times, parsing anew each time.
+fn measure(
+  puzzle: &Puzzle,
+  input: &str,
+  iterations: u32,
+) -> Vec<(Stage, anyhow::Result<Stats>)> {
+  let mut samples = Stage::ALL.map(|stage| (stage, Ok(Vec::new())));
+
+  for _ in 0..iterations {
+    let start = Instant::now();
+    let parsed = match puzzle.parse(black_box(input)) {
+      Ok(parsed) => parsed,
+      Err(err) => {
+        let err = format!("Failed parsing input: {err:#}");
+        return Stage::ALL
+          .into_iter()
+          .map(|stage| (stage, Err(anyhow::anyhow!("{err}"))))
+          .collect();
+      }
+    };
+    let elapsed = start.elapsed();
+
+    for (stage, stage_samples) in samples.iter_mut() {
+      let Ok(times) = stage_samples else {
+        continue;
+      };
+
+      let elapsed = match stage {
+        Stage::Parse => elapsed,
+        Stage::Solve(part) => {
+          let start = Instant::now();
+          if let Err(err) = black_box(puzzle.solve(&parsed, *part)) {
+            *stage_samples = Err(err);
+            continue;
+          }
+          start.elapsed()
+        }
+      };
+      times.push(elapsed);
+    }
+  }
+
+  samples
+    .into_iter()
+    .map(|(stage, samples)| (stage, samples.map(Stats::new)))
+    .collect()
+}
+
+/// Medians of earlier runs keyed by `year/day/stage`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Baseline(BTreeMap<String, Stats>);
+
+impl Baseline {
+  fn load(path: &Path) -> anyhow::Result<Self> {
+    if !path.exists() {
+      return Ok(Self::default());
+    }
+
+    let text = std::fs::read_to_string(path).with_context(|| {
+      format!("Failed reading baseline from {}", path.display())
+    })?;
+    serde_json::from_str(&text)
+      .with_context(|| format!("Invalid baseline in {}", path.display()))
+  }
+
+  fn save(&self, path: &Path) -> anyhow::Result<()> {
+    let text = serde_json::to_string_pretty(self)?;
+    std::fs::write(path, text)
+      .with_context(|| format!("Failed writing baseline to {}", path.display()))
+  }
+
+  fn key(year: Year, day: Day, stage: Stage) -> String {
+    format!("{year}/{day:02}/{stage}")
+  }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Stats {
+  median_ns: u64,
+  min_ns: u64,
+  max_ns: u64,
+}
+
+impl Stats {
+  fn new(mut samples: Vec<Duration>) -> Self {
+    samples.sort();
+    let nanos = |sample: Option<&Duration>| {
+      sample.map_or(0, |sample| {
+        u64::try_from(sample.as_nanos()).unwrap_or(u64::MAX)
+      })
+    };
+
+    Self {
+      median_ns: nanos(samples.get(samples.len().saturating_div(2))),
+      min_ns: nanos(samples.first()),
+      max_ns: nanos(samples.last()),
+    }
+  }
+
+  fn regressed(&self, baseline: &Stats, threshold: u32) -> bool {
+    let median = u128::from(self.median_ns).saturating_mul(100);
+    let allowed = u128::from(baseline.median_ns)
+      .saturating_mul(u128::from(threshold).saturating_add(100));
+    median > allowed
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+  Parse,
+  Solve(Part),
+}
+
+impl Stage {
+  const ALL: [Stage; 3] = [
+    Stage::Parse,
+    Stage::Solve(Part::One),
+    Stage::Solve(Part::Two),
+  ];
+}
+
+impl Display for Stage {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Stage::Parse => write!(f, "parse"),
+      Stage::Solve(part) => write!(f, "part{part}"),
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Row {
+  year: Year,
+  day: Day,
+  stage: Stage,
+  status: Status,
+  stats: anyhow::Result<Stats>,
+  previous: Option<Stats>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+  Ok,
+  Regressed,
+  New,
+  Fail,
+}
+
+impl Status {
+  fn of(
+    stats: &anyhow::Result<Stats>,
+    previous: Option<&Stats>,
+    threshold: u32,
+  ) -> Self {
+    match (stats, previous) {
+      (Err(_), _) => Status::Fail,
+      (Ok(_), None) => Status::New,
+      (Ok(stats), Some(previous)) if stats.regressed(previous, threshold) => {
+        Status::Regressed
+      }
+      (Ok(_), Some(_)) => Status::Ok,
+    }
+  }
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let status = match self {
+      Status::Ok => "ok",
+      Status::Regressed => "regressed",
+      Status::New => "new",
+      Status::Fail => "fail",
+    };
+    write!(f, "{status}")
+  }
+}
+
+fn print_table(rows: &[Row]) {
+  let header = [
+    "puzzle", "stage", "status", "median", "min", "max", "baseline", "change",
+  ];
+  let time = |nanos: u64| format!("{:.2?}", Duration::from_nanos(nanos));
+  let cells = rows
+    .iter()
+    .map(|row| {
+      let (median, min, max) = match &row.stats {
+        Ok(stats) => (
+          time(stats.median_ns),
+          time(stats.min_ns),
+          time(stats.max_ns),
+        ),
+        Err(err) => (format!("{err:#}"), String::new(), String::new()),
+      };
+      let (baseline, change) = match (&row.stats, row.previous) {
+        (Ok(stats), Some(previous)) => (
+          time(previous.median_ns),
+          change(previous.median_ns, stats.median_ns),
+        ),
+        (Err(_), Some(previous)) => (time(previous.median_ns), String::new()),
+        (_, None) => (String::new(), String::new()),
+      };
+
+      [
+        format!("{}/{}", row.year, row.day),
+        row.stage.to_string(),
+        row.status.to_string(),
+        median,
+        min,
+        max,
+        baseline,
+        change,
+      ]
+    })
+    .collect::<Vec<_>>();
+
+  table::print(header, &cells);
+}
+
+fn change(baseline: u64, median: u64) -> String {
+  if baseline == 0 {
+    return String::new();
+  }
+
+  let percent = (median as f64 / baseline as f64 - 1.0) * 100.0;
+  format!("{percent:+.1}%")
+}
+
+pub const BASELINE_PATH: &str =
+  concat!(env!("CARGO_MANIFEST_DIR"), "/../../bench.json");
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn millis(samples: &[u64]) -> Vec<Duration> {
+    samples.iter().copied().map(Duration::from_millis).collect()
+  }
+
+  #[test]
+  fn summarizes_samples() {
+    let noisy = Stats::new(millis(&[12, 10, 250, 11, 9]));
+    assert_eq!(
+      (noisy.median_ns, noisy.min_ns, noisy.max_ns),
+      (11_000_000, 9_000_000, 250_000_000)
+    );
+
+    let empty = Stats::new(Vec::new());
+    assert_eq!((empty.median_ns, empty.min_ns, empty.max_ns), (0, 0, 0));
+  }
+
+  #[test]
+  fn flags_regressions_past_threshold() {
+    let baseline = Stats::new(millis(&[100]));
+
+    assert!(!Stats::new(millis(&[110])).regressed(&baseline, 10));
+    assert!(Stats::new(millis(&[111])).regressed(&baseline, 10));
+    assert!(!Stats::new(millis(&[90])).regressed(&baseline, 0));
+
+    // NOTE: an outlier moves the maximum but not the median
+    let noisy = Stats::new(millis(&[105, 98, 1000, 101, 99]));
+    assert!(!noisy.regressed(&baseline, 10));
+
+    let stats = Ok(noisy);
+    assert_eq!(Status::of(&stats, None, 10), Status::New);
+    assert_eq!(Status::of(&stats, Some(&baseline), 10), Status::Ok);
+    assert_eq!(Status::of(&stats, Some(&baseline), 0), Status::Regressed);
+    assert_eq!(
+      Status::of(&Err(anyhow::anyhow!("Failed")), None, 10),
+      Status::Fail
+    );
+  }
+}
@@ -0,0 +1,296 @@
+use std::{
+  path::PathBuf,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use grind_core::{input, Day, Part, Year};
+
+use crate::config::{Config, SESSION_ENV};
+
+/// Talks to the Advent of Code server on behalf of one account.
+#[derive(Debug, Clone)]
+pub struct Client {
+  agent: ureq::Agent,
+  base_url: String,
+  session: String,
+  /// File remembering when the last request went out, shared between runs
+  throttle: Option<PathBuf>,
+  interval: Duration,
+}
+
+impl Client {
+  pub fn new(base_url: &str, session: &str) -> Self {
+    Self {
+      agent: ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(TIMEOUT)
+        .build(),
+      base_url: base_url.trim_end_matches('/').to_owned(),
+      session: session.to_owned(),
+      throttle: None,
+      interval: Duration::ZERO,
+    }
+  }
+
+  pub fn from_config(config: &Config) -> anyhow::Result<Self> {
+    let session = config.session.as_deref().ok_or_else(|| {
+      let path = Config::path()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| "the config file".to_owned());
+      anyhow::anyhow!(
+        "No session token, set {SESSION_ENV} or session in {path}"
+      )
+    })?;
+    let base_url = config.base_url.as_deref().unwrap_or(BASE_URL);
+
+    Ok(Self::new(base_url, session).throttled(
+      input::cache_dir().map(|dir| dir.join(THROTTLE_NAME)),
+      INTERVAL,
+    ))
+  }
+
+  /// Waits until `interval` passed since the last request recorded in
+  /// `throttle` before sending another one.
+  pub fn throttled(
+    self,
+    throttle: Option<PathBuf>,
+    interval: Duration,
+  ) -> Self {
+    Self {
+      throttle,
+      interval,
+      ..self
+    }
+  }
+
+  pub fn input(&self, year: Year, day: Day) -> anyhow::Result<String> {
+    ensure_unlocked(year, day)?;
+
+    let url = format!("{}/{year}/day/{day}/input", self.base_url);
+    let response = self.send(self.agent.get(&url), None, year, day)?;
+
+    response
+      .into_string()
+      .with_context(|| format!("Failed reading input of {year}/{day}"))
+  }
+
+  /// Posts `answer` and returns the page the server replies with.
+  pub fn answer(
+    &self,
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &str,
+  ) -> anyhow::Result<String> {
+    ensure_unlocked(year, day)?;
+
+    let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+    let level = part.number().to_string();
+    let form = [("level", level.as_str()), ("answer", answer)];
+    let response = self.send(self.agent.post(&url), Some(&form), year, day)?;
+
+    response
+      .into_string()
+      .with_context(|| format!("Failed reading verdict on {year}/{day}"))
+  }
+
+  fn send(
+    &self,
+    request: ureq::Request,
+    form: Option<&[(&str, &str)]>,
+    year: Year,
+    day: Day,
+  ) -> anyhow::Result<ureq::Response> {
+    self.wait();
+    let request = request.set("Cookie", &format!("session={}", self.session));
+    let response = match form {
+      Some(form) => request.send_form(form),
+      None => request.call(),
+    };
+
+    response.map_err(|err| match err {
+      ureq::Error::Status(404, _) => {
+        anyhow::anyhow!("{year}/{day} is not released yet")
+      }
+      ureq::Error::Status(400 | 401 | 403, _) => anyhow::anyhow!(
+        "Session token was rejected, log in again and update it"
+      ),
+      // NOTE: the server also fails like this on some malformed tokens
+      ureq::Error::Status(status @ 500..=599, _) => anyhow::anyhow!(
+        "Server failed with {status} for {year}/{day}, try again later or \
+         check the session token"
+      ),
+      ureq::Error::Status(status, _) => {
+        anyhow::anyhow!("Server answered {status} for {year}/{day}")
+      }
+      ureq::Error::Transport(err) => anyhow::Error::new(err)
+        .context(format!("Failed reaching {}", self.base_url)),
+    })
+  }
+
+  fn wait(&self) {
+    let Some(throttle) = &self.throttle else {
+      return;
+    };
+
+    let last = std::fs::read_to_string(throttle)
+      .ok()
+      .and_then(|text| text.trim().parse::<u64>().ok())
+      .and_then(|millis| UNIX_EPOCH.checked_add(Duration::from_millis(millis)));
+    if let Some(elapsed) = last.and_then(|last| last.elapsed().ok()) {
+      if let Some(remaining) = self.interval.checked_sub(elapsed) {
+        std::thread::sleep(remaining);
+      }
+    }
+
+    // NOTE: a lost timestamp only costs politeness so errors are ignored
+    let now = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|now| now.as_millis())
+      .unwrap_or_default();
+    if let Some(parent) = throttle.parent() {
+      let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = std::fs::write(throttle, now.to_string());
+  }
+}
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+pub fn unlocks_at(year: Year, day: Day) -> Option<SystemTime> {
+  let is_leap = |year: u64| {
+    year.is_multiple_of(4)
+      && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+  };
+  let year = u64::from(year);
+
+  let days = (1970..year)
+    .map(|year| if is_leap(year) { 366 } else { 365 })
+    .fold(0u64, u64::saturating_add)
+    .saturating_add(DAYS_BEFORE_DECEMBER)
+    .saturating_add(u64::from(is_leap(year)))
+    .saturating_add(u64::from(day.saturating_sub(1)));
+  let seconds = days
+    .saturating_mul(SECONDS_PER_DAY)
+    .saturating_add(UNLOCK_SECONDS);
+
+  UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+}
+
+fn ensure_unlocked(year: Year, day: Day) -> anyhow::Result<()> {
+  let unlocks_at = unlocks_at(year, day)
+    .ok_or_else(|| anyhow::anyhow!("{year}/{day} does not exist"))?;
+
+  match unlocks_at.duration_since(SystemTime::now()) {
+    Ok(remaining) => {
+      let minutes = remaining.as_secs().div_ceil(60);
+      Err(anyhow::anyhow!(
+        "{year}/{day} unlocks in {}h {}m",
+        minutes.saturating_div(60),
+        minutes.checked_rem(60).unwrap_or_default()
+      ))
+    }
+    Err(_) => Ok(()),
+  }
+}
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+  env!("CARGO_PKG_NAME"),
+  "/",
+  env!("CARGO_PKG_VERSION"),
+  " (",
+  env!("CARGO_PKG_REPOSITORY"),
+  " by ",
+  env!("CARGO_PKG_AUTHORS"),
+  ")"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+const THROTTLE_NAME: &str = "last-request";
+const INTERVAL: Duration = Duration::from_secs(5);
+
+const DAYS_BEFORE_DECEMBER: u64 = 334;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const UNLOCK_SECONDS: u64 = 5 * 60 * 60;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::stand_in::StandIn;
+
+  #[test]
+  fn fetches_input() -> anyhow::Result<()> {
+    let server = StandIn::serve(vec![(200, "1 2 3\n")])?;
+
+    let input = Client::new(&server.url, "token").input(2024, 1)?;
+    let requests = server.requests()?;
+
+    assert_eq!(input, "1 2 3\n");
+    assert!(requests[0].starts_with("GET /2024/day/1/input "));
+    assert!(requests[0].contains("session=token"));
+    assert!(requests[0].contains(USER_AGENT));
+
+    Ok(())
+  }
+
+  #[test]
+  fn explains_rejections() -> anyhow::Result<()> {
+    let server = StandIn::serve(vec![(404, ""), (400, ""), (503, "")])?;
+    let client = Client::new(&server.url, "token");
+
+    let unreleased = client.input(2024, 2).err().map(|err| err.to_string());
+    let rejected = client.input(2024, 3).err().map(|err| err.to_string());
+    let failed = client.input(2024, 4).err().map(|err| err.to_string());
+    server.requests()?;
+
+    assert_eq!(unreleased.as_deref(), Some("2024/2 is not released yet"));
+    assert_eq!(
+      rejected.as_deref(),
+      Some("Session token was rejected, log in again and update it")
+    );
+    assert!(failed.is_some_and(
+      |failed| failed.starts_with("Server failed with 503 for 2024/4")
+    ));
+
+    Ok(())
+  }
+
+  #[test]
+  fn throttles_requests() -> anyhow::Result<()> {
+    let server = StandIn::serve(vec![(200, ""), (200, "")])?;
+    let throttle = std::env::temp_dir()
+      .join(format!("grind-throttle-{}", std::process::id()));
+    let interval = Duration::from_millis(200);
+    let client = Client::new(&server.url, "token")
+      .throttled(Some(throttle.clone()), interval);
+
+    let start = std::time::Instant::now();
+    client.input(2024, 1)?;
+    client.input(2024, 2)?;
+    let elapsed = start.elapsed();
+    server.requests()?;
+    std::fs::remove_file(throttle)?;
+
+    assert!(elapsed >= interval);
+
+    Ok(())
+  }
+
+  #[test]
+  fn refuses_locked() {
+    assert!(Client::new("http://127.0.0.1:9", "token")
+      .input(Year::MAX, 25)
+      .is_err_and(|err| err.to_string().contains("unlocks in")));
+  }
+
+  #[test]
+  fn unlocks_at_midnight_est() {
+    assert_eq!(
+      unlocks_at(2024, 1),
+      UNIX_EPOCH.checked_add(Duration::from_secs(1_733_029_200))
+    );
+  }
+}
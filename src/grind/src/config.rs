@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+/// Settings from `config.toml` in the user config directory, overridden by
+/// the environment.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+  /// Session cookie of a logged in Advent of Code account
+  pub session: Option<String>,
+  /// Advent of Code server, handy for pointing at a stand-in
+  pub base_url: Option<String>,
+}
+
+impl Config {
+  pub fn load() -> anyhow::Result<Self> {
+    let mut config = match Self::path() {
+      Some(path) if path.exists() => {
+        let text = std::fs::read_to_string(&path).with_context(|| {
+          format!("Failed reading config from {}", path.display())
+        })?;
+        toml::from_str(&text)
+          .with_context(|| format!("Invalid config in {}", path.display()))?
+      }
+      _ => Self::default(),
+    };
+
+    if let Some(session) = env(SESSION_ENV) {
+      config.session = Some(session);
+    }
+    if let Some(base_url) = env(BASE_URL_ENV) {
+      config.base_url = Some(base_url);
+    }
+
+    Ok(config)
+  }
+
+  pub fn path() -> Option<PathBuf> {
+    env(CONFIG_ENV).map(PathBuf::from).or_else(|| {
+      dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(CONFIG_NAME))
+    })
+  }
+}
+
+fn env(name: &str) -> Option<String> {
+  std::env::var(name)
+    .ok()
+    .map(|value| value.trim().to_owned())
+    .filter(|value| !value.is_empty())
+}
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const CONFIG_ENV: &str = "GRIND_CONFIG";
+
+const CONFIG_DIR_NAME: &str = "grind";
+const CONFIG_NAME: &str = "config.toml";
@@ -0,0 +1,41 @@
+use anyhow::Context;
+use grind_core::{input, Day, Year};
+
+use crate::{client::Client, config::Config, puzzles};
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct FetchArgs {
+  /// Puzzle year
+  year: Year,
+
+  /// Puzzle day
+  #[arg(value_parser = puzzles::day_parser())]
+  day: Day,
+
+  /// Download again even when the input is cached
+  #[arg(long)]
+  force: bool,
+}
+
+pub fn fetch(args: &FetchArgs) -> anyhow::Result<()> {
+  let path = input::cache_path(args.year, args.day)
+    .ok_or_else(|| anyhow::anyhow!("No cache directory for inputs"))?;
+  if path.exists() && !args.force {
+    println!("{}/{} is cached in {}", args.year, args.day, path.display());
+    return Ok(());
+  }
+
+  let client = Client::from_config(&Config::load()?)?;
+  let text = client.input(args.year, args.day)?;
+
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)
+      .with_context(|| format!("Failed creating {}", parent.display()))?;
+  }
+  std::fs::write(&path, text)
+    .with_context(|| format!("Failed writing {}", path.display()))?;
+
+  println!("Fetched {}/{} into {}", args.year, args.day, path.display());
+
+  Ok(())
+}
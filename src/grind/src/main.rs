@@ -0,0 +1,59 @@
+#![deny(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::todo)]
+#![deny(clippy::unreachable)]
+#![deny(clippy::allow_attributes_without_reason)]
+
+mod answers;
+mod attempts;
+mod bench;
+mod client;
+mod config;
+mod fetch;
+mod new;
+mod puzzles;
+mod run;
+#[cfg(test)]
+mod stand_in;
+mod submit;
+mod table;
+mod verify;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Clone, Parser)]
+#[command(version, about)]
+struct Args {
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum Command {
+  /// Solve one puzzle or all of them
+  Run(run::RunArgs),
+  /// Check answers against the ones confirmed correct
+  Verify(verify::VerifyArgs),
+  /// Time parse and both parts against the saved baseline
+  Bench(bench::BenchArgs),
+  /// Scaffold the crate of a new day and register it with the runner
+  New(new::NewArgs),
+  /// Download the input of a day into the cache
+  Fetch(fetch::FetchArgs),
+  /// Submit the answer of a part and remember how it went
+  Submit(submit::SubmitArgs),
+}
+
+fn main() -> anyhow::Result<()> {
+  let args = Args::parse();
+
+  match &args.command {
+    Command::Run(args) => run::run(args),
+    Command::Verify(args) => verify::verify(args),
+    Command::Bench(args) => bench::bench(args),
+    Command::New(args) => new::new(args),
+    Command::Fetch(args) => fetch::fetch(args),
+    Command::Submit(args) => submit::submit(args),
+  }
+}
@@ -0,0 +1,311 @@
+use std::path::Path;
+
+use anyhow::Context;
+use grind_core::{Day, Year};
+
+use crate::puzzles;
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct NewArgs {
+  /// Puzzle year
+  #[arg(value_parser = clap::value_parser!(Year).range(2015..))]
+  year: Year,
+
+  /// Puzzle day
+  #[arg(value_parser = puzzles::day_parser())]
+  day: Day,
+}
+
+pub fn new(args: &NewArgs) -> anyhow::Result<()> {
+  let name = create(Path::new(ROOT_PATH), args.year, args.day)?;
+
+  println!("Created src/{name}");
+
+  Ok(())
+}
+
+/// Writes the crate of a day into the repository at `root` and registers it
+/// with the runner, leaving everything as it was when a step fails.
+fn create(root: &Path, year: Year, day: Day) -> anyhow::Result<String> {
+  let name = crate_name(year, day);
+  let dir = root.join("src").join(&name);
+  if dir.exists() {
+    return Err(anyhow::anyhow!("{name} already exists"));
+  }
+
+  let module = name.replace('-', "_");
+  let manifest_path = root.join(MANIFEST_PATH);
+  let manifest_text = read(&manifest_path)?;
+  let manifest = register(
+    &manifest_text,
+    &format!("{name} = {{ path = \"../{name}\" }}"),
+    |line| line.starts_with("aoc-"),
+  )
+  .with_context(|| format!("Failed registering {name} in the runner"))?;
+  let puzzles_path = root.join(PUZZLES_PATH);
+  let puzzles_text = read(&puzzles_path)?;
+  let puzzles =
+    register(&puzzles_text, &format!("  {module}::PUZZLE,"), |line| {
+      line.trim_start().starts_with("aoc_")
+    })
+    .with_context(|| format!("Failed registering {name} in the runner"))?;
+
+  // NOTE: the crate is written aside and moved in place at once so a failed
+  // write never leaves half of it in the workspace
+  let staging = root.join(STAGING_PATH).join(&name);
+  let created = stage(&staging, &render(year, day))
+    .and_then(|()| {
+      std::fs::rename(&staging, &dir)
+        .with_context(|| format!("Failed moving {name} into src"))
+    })
+    .and_then(|()| write(&manifest_path, &manifest))
+    .and_then(|()| write(&puzzles_path, &puzzles));
+  if let Err(err) = created {
+    let undone = [
+      remove_dir(&staging),
+      remove_dir(&dir),
+      write(&manifest_path, &manifest_text),
+      write(&puzzles_path, &puzzles_text),
+    ];
+    return match undone.into_iter().find_map(Result::err) {
+      Some(undo) => {
+        Err(err.context(format!("Failed undoing {name}: {undo:#}")))
+      }
+      None => Err(err),
+    };
+  }
+
+  Ok(name)
+}
+
+fn crate_name(year: Year, day: Day) -> String {
+  format!("aoc-{year}-day-{day:02}")
+}
+
+/// Files of the crate of a day relative to its directory.
+fn render(year: Year, day: Day) -> Vec<(&'static str, String)> {
+  let name = crate_name(year, day);
+  let module = name.replace('-', "_");
+  let render = |template: &str| {
+    template
+      .replace("{{name}}", &name)
+      .replace("{{module}}", &module)
+      .replace("{{year}}", &year.to_string())
+      .replace("{{day}}", &day.to_string())
+      .replace("{{padded_day}}", &format!("{day:02}"))
+  };
+
+  vec![
+    (
+      "Cargo.toml",
+      render(include_str!("../templates/Cargo.toml.tmpl")),
+    ),
+    (
+      "README.md",
+      render(include_str!("../templates/README.md.tmpl")),
+    ),
+    (
+      "src/main.rs",
+      render(include_str!("../templates/main.rs.tmpl")),
+    ),
+    (
+      "src/lib.rs",
+      render(include_str!("../templates/lib.rs.tmpl")),
+    ),
+    ("input.txt", String::new()),
+    ("examples/example.txt", String::new()),
+    (
+      "examples/example.toml",
+      render(include_str!("../templates/example.toml.tmpl")),
+    ),
+  ]
+}
+
+/// Writes `files` into a fresh `dir`.
+fn stage(dir: &Path, files: &[(&str, String)]) -> anyhow::Result<()> {
+  remove_dir(dir)?;
+  for (path, text) in files {
+    write(&dir.join(path), text)?;
+  }
+  Ok(())
+}
+
+/// Inserts `entry` among the lines matching `is_entry` keeping them sorted.
+fn register(
+  text: &str,
+  entry: &str,
+  is_entry: impl Fn(&str) -> bool,
+) -> anyhow::Result<String> {
+  let mut lines = text.lines().collect::<Vec<_>>();
+  let entries = lines
+    .iter()
+    .enumerate()
+    .filter(|(_, line)| is_entry(line))
+    .map(|(index, line)| (index, *line))
+    .collect::<Vec<_>>();
+
+  if entries.iter().any(|(_, line)| *line == entry) {
+    return Err(anyhow::anyhow!("{:?} is already registered", entry.trim()));
+  }
+
+  let index = match entries.iter().find(|(_, line)| *line > entry) {
+    Some((index, _)) => *index,
+    None => entries
+      .last()
+      .map(|(index, _)| index.saturating_add(1))
+      .ok_or_else(|| anyhow::anyhow!("No puzzles registered yet"))?,
+  };
+  lines.insert(index, entry);
+
+  let mut text = lines.join("\n");
+  text.push('\n');
+  Ok(text)
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+  std::fs::read_to_string(path)
+    .with_context(|| format!("Failed reading {}", path.display()))
+}
+
+fn remove_dir(path: &Path) -> anyhow::Result<()> {
+  if !path.exists() {
+    return Ok(());
+  }
+  std::fs::remove_dir_all(path)
+    .with_context(|| format!("Failed removing {}", path.display()))
+}
+
+fn write(path: &Path, text: &str) -> anyhow::Result<()> {
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)
+      .with_context(|| format!("Failed creating {}", parent.display()))?;
+  }
+  std::fs::write(path, text)
+    .with_context(|| format!("Failed writing {}", path.display()))
+}
+
+const ROOT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+const MANIFEST_PATH: &str = "src/grind/Cargo.toml";
+const PUZZLES_PATH: &str = "src/grind/src/puzzles.rs";
+const STAGING_PATH: &str = "target/grind-new";
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use super::*;
+
+  #[test]
+  fn registers_in_order() -> anyhow::Result<()> {
+    let text = "[dependencies]\n\
+      anyhow = \"1\"\n\
+      aoc-2024-day-01 = { path = \"../aoc-2024-day-01\" }\n\
+      aoc-2024-day-03 = { path = \"../aoc-2024-day-03\" }\n\
+      clap = \"4\"\n";
+    let entry = "aoc-2024-day-02 = { path = \"../aoc-2024-day-02\" }";
+
+    let text = register(text, entry, |line| line.starts_with("aoc-"))?;
+    assert_eq!(text.lines().nth(3), Some(entry));
+
+    Ok(())
+  }
+
+  #[test]
+  fn registers_after_last() -> anyhow::Result<()> {
+    let text = "&[\n  aoc_2024_day_01::PUZZLE,\n];\n";
+    let entry = "  aoc_2025_day_01::PUZZLE,";
+
+    let text =
+      register(text, entry, |line| line.trim_start().starts_with("aoc_"))?;
+    assert_eq!(text.lines().nth(2), Some(entry));
+
+    Ok(())
+  }
+
+  /// Repository with just the files `create` registers days in.
+  fn workspace(name: &str) -> anyhow::Result<PathBuf> {
+    let root =
+      std::env::temp_dir().join(format!("grind-{name}-{}", std::process::id()));
+    remove_dir(&root)?;
+    write(
+      &root.join(MANIFEST_PATH),
+      "[dependencies]\n\
+        aoc-2024-day-01 = { path = \"../aoc-2024-day-01\" }\n",
+    )?;
+    write(
+      &root.join(PUZZLES_PATH),
+      "&[\n  aoc_2024_day_01::PUZZLE,\n];\n",
+    )?;
+    Ok(root)
+  }
+
+  #[test]
+  fn renders_templates() -> anyhow::Result<()> {
+    let files = render(2024, 7);
+    let file = |path: &str| {
+      files
+        .iter()
+        .find(|(file, _)| *file == path)
+        .map(|(_, text)| text.as_str())
+        .unwrap_or_default()
+    };
+
+    assert!(files.iter().all(|(_, text)| !text.contains("{{")));
+    assert!(file("Cargo.toml").contains("name = \"aoc-2024-day-07\""));
+    assert!(file("src/main.rs").contains("aoc_2024_day_07::PUZZLE"));
+    assert!(file("src/lib.rs").contains("impl Solution for Day07 {"));
+    assert!(file("src/lib.rs").contains("const DAY: Day = 7;"));
+    assert_eq!(file("input.txt"), "");
+
+    // NOTE: parts without an answer are skipped until one is filled in
+    let example = toml::from_str::<toml::Table>(file("examples/example.toml"))?;
+    assert!(example.is_empty());
+
+    // NOTE: generated sources stay within the 80 columns rustfmt allows
+    assert!(files
+      .iter()
+      .filter(|(path, _)| path.ends_with(".rs"))
+      .flat_map(|(_, text)| text.lines())
+      .all(|line| line.chars().count() <= 80));
+
+    Ok(())
+  }
+
+  #[test]
+  fn creates_crate() -> anyhow::Result<()> {
+    let root = workspace("creates-crate")?;
+
+    assert_eq!(create(&root, 2024, 2)?, "aoc-2024-day-02");
+    let dir = root.join("src/aoc-2024-day-02");
+    assert!(dir.join("src/lib.rs").exists() && dir.join("input.txt").exists());
+    assert!(read(&root.join(PUZZLES_PATH))?.contains("aoc_2024_day_02"));
+    assert!(!root.join(STAGING_PATH).join("aoc-2024-day-02").exists());
+    assert!(create(&root, 2024, 2).is_err());
+
+    remove_dir(&root)
+  }
+
+  #[test]
+  fn leaves_nothing_on_failure() -> anyhow::Result<()> {
+    let root = workspace("leaves-nothing")?;
+    let manifest = read(&root.join(MANIFEST_PATH))?;
+    // NOTE: a file where the staging directory goes fails the first write
+    write(&root.join(STAGING_PATH), "")?;
+
+    assert!(create(&root, 2024, 2).is_err());
+    assert!(!root.join("src/aoc-2024-day-02").exists());
+    assert_eq!(read(&root.join(MANIFEST_PATH))?, manifest);
+
+    remove_dir(&root)
+  }
+
+  #[test]
+  fn refuses_registered() {
+    let text = "  aoc_2024_day_01::PUZZLE,\n";
+
+    assert!(register(text, "  aoc_2024_day_01::PUZZLE,", |line| {
+      line.trim_start().starts_with("aoc_")
+    })
+    .is_err());
+  }
+}
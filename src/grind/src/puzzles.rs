@@ -0,0 +1,59 @@
+use grind_core::{Day, Puzzle, Year};
+
+pub const PUZZLES: &[Puzzle] = &[
+  aoc_2024_day_01::PUZZLE,
+  aoc_2024_day_02::PUZZLE,
+  aoc_2024_day_03::PUZZLE,
+  aoc_2024_day_04::PUZZLE,
+  aoc_2024_day_05::PUZZLE,
+  aoc_2024_day_06::PUZZLE,
+  aoc_2024_day_07::PUZZLE,
+  aoc_2024_day_08::PUZZLE,
+  aoc_2024_day_09::PUZZLE,
+  aoc_2024_day_10::PUZZLE,
+  aoc_2024_day_11::PUZZLE,
+  aoc_2024_day_12::PUZZLE,
+  aoc_2024_day_13::PUZZLE,
+  aoc_2024_day_14::PUZZLE,
+  aoc_2024_day_15::PUZZLE,
+  aoc_2024_day_16::PUZZLE,
+  aoc_2025_day_01::PUZZLE,
+  aoc_2025_day_02::PUZZLE,
+  aoc_2025_day_03::PUZZLE,
+  aoc_2025_day_04::PUZZLE,
+  aoc_2025_day_05::PUZZLE,
+  aoc_2025_day_06::PUZZLE,
+  aoc_2025_day_07::PUZZLE,
+  aoc_2025_day_08::PUZZLE,
+  aoc_2025_day_09::PUZZLE,
+  aoc_2025_day_10::PUZZLE,
+];
+
+pub fn find(year: Year, day: Day) -> anyhow::Result<&'static Puzzle> {
+  PUZZLES
+    .iter()
+    .find(|puzzle| puzzle.year == year && puzzle.day == day)
+    .ok_or_else(|| anyhow::anyhow!("No solution for {year}/{day}"))
+}
+
+/// Every puzzle of the given year and day, leaving out either selects all.
+pub fn select(
+  year: Option<Year>,
+  day: Option<Day>,
+) -> anyhow::Result<Vec<&'static Puzzle>> {
+  if let (Some(year), Some(day)) = (year, day) {
+    return Ok(vec![find(year, day)?]);
+  }
+
+  Ok(
+    PUZZLES
+      .iter()
+      .filter(|puzzle| year.is_none_or(|year| puzzle.year == year))
+      .collect(),
+  )
+}
+
+/// Parses a day of the advent, which runs from the 1st to the 25th.
+pub fn day_parser() -> clap::builder::RangedI64ValueParser<Day> {
+  clap::value_parser!(Day).range(1..=25)
+}
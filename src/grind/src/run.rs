@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use grind_core::{
+  input,
+  input::InputArgs,
+  output::{self, Format},
+  Day, Part, Year,
+};
+
+use crate::puzzles;
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct RunArgs {
+  /// Puzzle year, narrows down `--all` when given
+  #[arg(required_unless_present = "all")]
+  year: Option<Year>,
+
+  /// Puzzle day
+  #[arg(required_unless_present = "all", conflicts_with = "all")]
+  day: Option<Day>,
+
+  /// Solve only this part
+  #[arg(short, long)]
+  part: Option<Part>,
+
+  /// Solve every puzzle with its own input
+  #[arg(short, long)]
+  all: bool,
+
+  /// Puzzle input file, `-` reads standard input
+  #[arg(short, long, value_name = "PATH", conflicts_with = "all")]
+  input: Option<PathBuf>,
+
+  /// How to print the answers
+  #[arg(short, long, value_enum, default_value_t)]
+  format: Format,
+}
+
+pub fn run(args: &RunArgs) -> anyhow::Result<()> {
+  let puzzles = puzzles::select(args.year, args.day)?;
+
+  let parts = Part::or_all(args.part);
+  let mut failed = 0usize;
+  for puzzle in puzzles {
+    let input = if args.all {
+      input::read_file(&input::default_path(puzzle))
+    } else {
+      input::resolve(
+        &InputArgs {
+          input: args.input.clone(),
+        },
+        input::default_path(puzzle),
+      )
+    };
+    let input = match input {
+      Ok(input) => input,
+      Err(err) => {
+        failed = failed.saturating_add(1);
+        output::print_failed(puzzle, &parts, &err, args.format);
+        continue;
+      }
+    };
+
+    let reports = output::print(puzzle, &input, &parts, args.format);
+    failed = failed.saturating_add(
+      reports
+        .iter()
+        .filter(|report| report.answer.is_err())
+        .count(),
+    );
+  }
+
+  if failed > 0 {
+    return Err(anyhow::anyhow!("{failed} failed"));
+  }
+
+  Ok(())
+}
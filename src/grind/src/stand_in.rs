@@ -0,0 +1,69 @@
+use std::{
+  io::{BufRead, BufReader, Read, Write},
+  net::TcpListener,
+  thread::JoinHandle,
+};
+
+/// Local stand-in for the Advent of Code server.
+pub struct StandIn {
+  pub url: String,
+  handle: JoinHandle<anyhow::Result<Vec<String>>>,
+}
+
+impl StandIn {
+  /// Answers each incoming request in turn with a status and body.
+  pub fn serve(responses: Vec<(u16, &'static str)>) -> anyhow::Result<Self> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}", listener.local_addr()?);
+
+    let handle = std::thread::spawn(move || {
+      let mut requests = Vec::new();
+      for (status, body) in responses {
+        let (mut stream, _) = listener.accept()?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        let mut request = String::new();
+        let mut length = 0usize;
+        loop {
+          let mut line = String::new();
+          reader.read_line(&mut line)?;
+          if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+              length = value.trim().parse()?;
+            }
+          }
+          request.push_str(&line);
+          if line.trim().is_empty() {
+            break;
+          }
+        }
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content)?;
+        request.push_str(&String::from_utf8_lossy(&content));
+        requests.push(request);
+
+        write!(
+          stream,
+          "HTTP/1.1 {status} Stand-in\r\n\
+            Content-Type: text/plain\r\n\
+            Content-Length: {}\r\n\
+            Connection: close\r\n\
+            \r\n\
+            {body}",
+          body.len()
+        )?;
+      }
+      Ok(requests)
+    });
+
+    Ok(Self { url, handle })
+  }
+
+  /// Waits until every response went out and returns what was requested.
+  pub fn requests(self) -> anyhow::Result<Vec<String>> {
+    self
+      .handle
+      .join()
+      .map_err(|_| anyhow::anyhow!("Stand-in server crashed"))?
+  }
+}
@@ -0,0 +1,141 @@
+use std::{
+  path::PathBuf,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use grind_core::{input, Day, Part, Year};
+
+use crate::{
+  answers::{Answers, ANSWERS_PATH},
+  attempts::{Attempt, Attempts, Outcome, ATTEMPTS_NAME},
+  client::Client,
+  config::Config,
+  puzzles,
+};
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct SubmitArgs {
+  /// Puzzle year
+  year: Year,
+
+  /// Puzzle day
+  #[arg(value_parser = puzzles::day_parser())]
+  day: Day,
+
+  /// Puzzle part
+  part: Part,
+}
+
+pub fn submit(args: &SubmitArgs) -> anyhow::Result<()> {
+  let puzzle = puzzles::find(args.year, args.day)?;
+  let text = input::read_file(&input::default_path(puzzle))?;
+  let answer = puzzle
+    .run(&text, &[args.part])
+    .into_iter()
+    .next()
+    .ok_or_else(|| anyhow::anyhow!("Nothing to submit"))?
+    .answer?;
+
+  let attempts_path = input::cache_dir()
+    .map(|dir| dir.join(ATTEMPTS_NAME))
+    .ok_or_else(|| anyhow::anyhow!("No cache directory for attempts"))?;
+  let mut attempts = Attempts::load(&attempts_path)?;
+
+  let client = Client::from_config(&Config::load()?)?;
+  let outcome = send(
+    &client,
+    &mut attempts,
+    args.year,
+    args.day,
+    args.part,
+    &answer,
+    now(),
+  )?;
+  attempts.save(&attempts_path)?;
+
+  println!(
+    "{}/{}/{}: {answer} is {outcome}",
+    args.year, args.day, args.part
+  );
+
+  if outcome != Outcome::Correct {
+    return Err(anyhow::anyhow!("Answer was not accepted"));
+  }
+
+  let answers_path = PathBuf::from(ANSWERS_PATH);
+  let mut answers = Answers::load(&answers_path)?;
+  answers.set(args.year, args.day, args.part, answer);
+  answers.save(&answers_path)
+}
+
+/// Submits `answer` unless earlier attempts already rule it out and records
+/// the outcome.
+fn send(
+  client: &Client,
+  attempts: &mut Attempts,
+  year: Year,
+  day: Day,
+  part: Part,
+  answer: &str,
+  now: u64,
+) -> anyhow::Result<Outcome> {
+  if let Some(refusal) = attempts.refusal(year, day, part, answer, now) {
+    return Err(anyhow::anyhow!("Not submitting {answer}, {refusal}"));
+  }
+
+  let page = client.answer(year, day, part, answer)?;
+  let outcome = Outcome::parse(&page)?;
+  attempts.push(
+    year,
+    day,
+    part,
+    Attempt {
+      answer: answer.to_owned(),
+      outcome,
+      at: now,
+    },
+  );
+
+  Ok(outcome)
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|now| now.as_secs())
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::stand_in::StandIn;
+
+  #[test]
+  fn records_attempts() -> anyhow::Result<()> {
+    let server = StandIn::serve(vec![
+      (
+        200,
+        "<p>That's not the right answer; your answer is too high.</p>",
+      ),
+      (200, "<p>That's the right answer!</p>"),
+    ])?;
+    let client = Client::new(&server.url, "token");
+    let mut attempts = Attempts::default();
+
+    let high = send(&client, &mut attempts, 2024, 1, Part::Two, "42", 0)?;
+    let higher = send(&client, &mut attempts, 2024, 1, Part::Two, "43", 0);
+    let correct = send(&client, &mut attempts, 2024, 1, Part::Two, "41", 0)?;
+    let requests = server.requests()?;
+
+    assert_eq!(high, Outcome::TooHigh);
+    assert!(higher.is_err());
+    assert_eq!(correct, Outcome::Correct);
+    assert_eq!(attempts.get(2024, 1, Part::Two).len(), 2);
+    assert!(requests[0].starts_with("POST /2024/day/1/answer "));
+    assert!(requests[0].ends_with("level=2&answer=42"));
+    assert!(requests[1].ends_with("level=2&answer=41"));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,24 @@
+/// Prints rows with every column padded to its widest cell.
+pub fn print<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+  let mut widths = header.map(str::len);
+  for row in rows.iter() {
+    for (width, cell) in widths.iter_mut().zip(row.iter()) {
+      *width = (*width).max(cell.chars().count());
+    }
+  }
+
+  let print_row = |row: &[String]| {
+    let line = row
+      .iter()
+      .zip(widths.iter())
+      .map(|(cell, width)| format!("{cell:width$}"))
+      .collect::<Vec<_>>()
+      .join("  ");
+    println!("{}", line.trim_end());
+  };
+
+  print_row(&header.map(str::to_owned));
+  for row in rows.iter() {
+    print_row(row);
+  }
+}
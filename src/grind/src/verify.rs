@@ -0,0 +1,236 @@
+use std::{fmt::Display, path::PathBuf, time::Duration};
+
+use grind_core::{input, Day, Part, Year};
+
+use crate::{
+  answers::{Answers, ANSWERS_PATH},
+  puzzles, table,
+};
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct VerifyArgs {
+  /// Puzzle year, verifies every year when left out
+  year: Option<Year>,
+
+  /// Puzzle day, verifies every day of the year when left out
+  #[arg(requires = "year")]
+  day: Option<Day>,
+
+  /// Verify only this part
+  #[arg(short, long)]
+  part: Option<Part>,
+
+  /// Record the answers as correct instead of checking them
+  #[arg(long, requires = "day")]
+  record: bool,
+
+  /// Let `--record` replace answers that differ from the recorded ones
+  #[arg(long, requires = "record")]
+  force: bool,
+
+  /// Answers file, defaults to `answers.toml` in the repository root
+  #[arg(long, value_name = "PATH")]
+  answers: Option<PathBuf>,
+}
+
+pub fn verify(args: &VerifyArgs) -> anyhow::Result<()> {
+  let answers_path = args
+    .answers
+    .clone()
+    .unwrap_or_else(|| PathBuf::from(ANSWERS_PATH));
+  let mut answers = Answers::load(&answers_path)?;
+
+  let mut rows = Vec::new();
+  for puzzle in puzzles::select(args.year, args.day)? {
+    let parts = Part::or_all(args.part);
+    let reports = match input::read_file(&input::default_path(puzzle)) {
+      Ok(input) => puzzle.run(&input, &parts),
+      Err(err) => {
+        for part in parts {
+          rows.push(Row {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            status: Status::Fail,
+            answer: format!("{err:#}"),
+            expected: String::new(),
+            elapsed: Duration::ZERO,
+          });
+        }
+        continue;
+      }
+    };
+
+    for report in reports {
+      let expected = answers.get(report.year, report.day, report.part);
+      let elapsed = report.elapsed();
+      let status = Status::of(&report.answer, expected, args);
+      let answer = match report.answer {
+        Ok(answer) => answer,
+        Err(err) => format!("{err:#}"),
+      };
+
+      rows.push(Row {
+        year: report.year,
+        day: report.day,
+        part: report.part,
+        expected: expected.unwrap_or_default().to_owned(),
+        status,
+        answer,
+        elapsed,
+      });
+    }
+  }
+
+  if args.record {
+    for row in rows.iter().filter(|row| row.status == Status::Recorded) {
+      answers.set(row.year, row.day, row.part, row.answer.clone());
+    }
+    answers.save(&answers_path)?;
+  }
+
+  print_table(&rows);
+
+  let count =
+    |status: Status| rows.iter().filter(|row| row.status == status).count();
+  let conflicts = count(Status::Conflict);
+  let failed = count(Status::Fail).saturating_add(conflicts);
+  println!(
+    "\n{} passed, {failed} failed, {} missing, {} recorded",
+    count(Status::Pass),
+    count(Status::Missing),
+    count(Status::Recorded),
+  );
+
+  if conflicts > 0 {
+    return Err(anyhow::anyhow!(
+      "Answers of {conflicts} parts differ from the recorded ones, pass --force to \
+       replace them"
+    ));
+  }
+  if failed > 0 {
+    return Err(anyhow::anyhow!("{failed} failed"));
+  }
+
+  Ok(())
+}
+
+#[derive(Debug, Clone)]
+struct Row {
+  year: Year,
+  day: Day,
+  part: Part,
+  status: Status,
+  answer: String,
+  expected: String,
+  elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+  Pass,
+  Fail,
+  Missing,
+  Recorded,
+  /// Recording would replace a different answer.
+  Conflict,
+}
+
+impl Status {
+  fn of(
+    answer: &anyhow::Result<String>,
+    expected: Option<&str>,
+    args: &VerifyArgs,
+  ) -> Self {
+    match (answer, expected) {
+      (Err(_), _) => Status::Fail,
+      (Ok(answer), Some(expected))
+        if args.record && !args.force && answer != expected =>
+      {
+        Status::Conflict
+      }
+      (Ok(_), _) if args.record => Status::Recorded,
+      (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+      (Ok(_), Some(_)) => Status::Fail,
+      (Ok(_), None) => Status::Missing,
+    }
+  }
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let status = match self {
+      Status::Pass => "pass",
+      Status::Fail => "fail",
+      Status::Missing => "missing",
+      Status::Recorded => "recorded",
+      Status::Conflict => "conflict",
+    };
+    write!(f, "{status}")
+  }
+}
+
+fn print_table(rows: &[Row]) {
+  let header = ["puzzle", "part", "status", "answer", "expected", "time"];
+  let cells = rows
+    .iter()
+    .map(|row| {
+      [
+        format!("{}/{}", row.year, row.day),
+        row.part.to_string(),
+        row.status.to_string(),
+        row.answer.clone(),
+        row.expected.clone(),
+        format!("{:.2?}", row.elapsed),
+      ]
+    })
+    .collect::<Vec<_>>();
+
+  table::print(header, &cells);
+}
+
+#[cfg(test)]
+mod tests {
+  use clap::Parser;
+
+  use super::*;
+
+  #[derive(Debug, Parser)]
+  struct Args {
+    #[command(flatten)]
+    verify: VerifyArgs,
+  }
+
+  fn status(
+    answer: &str,
+    expected: Option<&str>,
+    args: &[&str],
+  ) -> anyhow::Result<Status> {
+    let args =
+      Args::try_parse_from(["verify", "2024", "1"].iter().chain(args))?;
+    Ok(Status::of(&Ok(answer.to_owned()), expected, &args.verify))
+  }
+
+  #[test]
+  fn checks_answers() -> anyhow::Result<()> {
+    assert_eq!(status("7", Some("7"), &[])?, Status::Pass);
+    assert_eq!(status("8", Some("7"), &[])?, Status::Fail);
+    assert_eq!(status("8", None, &[])?, Status::Missing);
+
+    Ok(())
+  }
+
+  #[test]
+  fn records_only_new_or_forced_answers() -> anyhow::Result<()> {
+    assert_eq!(status("8", None, &["--record"])?, Status::Recorded);
+    assert_eq!(status("7", Some("7"), &["--record"])?, Status::Recorded);
+    assert_eq!(status("8", Some("7"), &["--record"])?, Status::Conflict);
+    assert_eq!(
+      status("8", Some("7"), &["--record", "--force"])?,
+      Status::Recorded
+    );
+    assert!(status("8", Some("7"), &["--force"]).is_err());
+
+    Ok(())
+  }
+}